@@ -0,0 +1,179 @@
+//! a string of up to `N` bytes, stored inline.
+
+use core::{borrow::Borrow, fmt, hash::Hash, ops::Deref, str};
+use crate::{IString, SmallString, CapacityError};
+
+/// a string of up to `N` bytes that never allocates, like a `TinyString` with a chosen capacity.
+///
+/// `N` can be at most 255, larger values fail to compile.
+/// ```compile_fail
+/// let _ = istring::ArrayString::<256>::new("");
+/// ```
+#[derive(Copy, Clone)]
+pub struct ArrayString<const N: usize> {
+    len: u8,
+    buf: [u8; N],
+}
+
+impl<const N: usize> ArrayString<N> {
+    /// the maximum length of a string that can be stored.
+    pub const CAPACITY: usize = N;
+
+    /// copy `s`, or return `None` if it is longer than `N` bytes.
+    #[inline]
+    pub const fn new(s: &str) -> Option<Self> {
+        const { assert!(N <= 255, "ArrayString holds at most 255 bytes") };
+        let bytes = s.as_bytes();
+        let len = bytes.len();
+        if len > N {
+            return None;
+        }
+        let mut buf = [0; N];
+        let mut i = 0;
+        while i < len {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Some(ArrayString { len: len as u8, buf })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self
+    }
+
+    /// append `s` if it fits, otherwhise leave `self` unchanged and return an error.
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let len = self.len as usize;
+        let new_len = len + s.len();
+        if new_len > N {
+            return Err(CapacityError { needed: s.len(), available: N - len, rejected: None });
+        }
+        self.buf[len .. new_len].copy_from_slice(s.as_bytes());
+        self.len = new_len as u8;
+        Ok(())
+    }
+
+    /// append `ch` if it fits, otherwhise leave `self` unchanged and return an error.
+    ///
+    /// the error gives `ch` back through `CapacityError::rejected_char`.
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError> {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf))
+            .map_err(|e| CapacityError { rejected: Some(ch), ..e })
+    }
+
+    /// the number of bytes that can still be appended.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        N - self.len as usize
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for ArrayString<N> {
+    #[inline]
+    fn default() -> Self {
+        ArrayString::new("").unwrap()
+    }
+}
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(&self.buf[.. self.len as usize])
+        }
+    }
+}
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+impl<const N: usize> AsRef<[u8]> for ArrayString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl<const N: usize> Borrow<str> for ArrayString<N> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+/// appending fails with `fmt::Error` if the string does not fit.
+impl<const N: usize> fmt::Write for ArrayString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize, T: AsRef<str>> PartialEq<T> for ArrayString<N> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.as_str().eq(other.as_ref())
+    }
+}
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize, T: AsRef<str>> PartialOrd<T> for ArrayString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_ref())
+    }
+}
+impl<const N: usize> Ord for ArrayString<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+/// hashes exactly like `str`, see `IString`.
+impl<const N: usize> Hash for ArrayString<N> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// returns the original string if it is longer than `N` bytes.
+impl<'a, const N: usize> TryFrom<&'a str> for ArrayString<N> {
+    type Error = &'a str;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, &'a str> {
+        ArrayString::new(s).ok_or(s)
+    }
+}
+impl<const N: usize> From<ArrayString<N>> for IString {
+    #[inline]
+    fn from(s: ArrayString<N>) -> IString {
+        IString::from(s.as_str())
+    }
+}
+impl<const N: usize> From<ArrayString<N>> for SmallString {
+    #[inline]
+    fn from(s: ArrayString<N>) -> SmallString {
+        SmallString::from(s.as_str())
+    }
+}
@@ -1,6 +1,9 @@
 macro_rules! define_common_bytes {
     ($name:ident, $union:ident) => {
 impl $name {
+    /// the maximum number of bytes that can be stored without a heap allocation.
+    pub const INLINE_CAPACITY: usize = INLINE_CAPACITY;
+
     /// view as Inline.
     ///
     /// Panics if the string isn't inlined
@@ -19,8 +22,17 @@ impl $name {
         &mut self.union.heap
     }
 
-    //#[inline]
-    //pub fn as_inline_or_heap(self) 
+    /// the complete inline buffer, or `None` if not inline.
+    ///
+    /// bytes past `len()` are guaranteed to be zero.
+    #[inline]
+    pub fn inline_bytes(&self) -> Option<&[u8; INLINE_CAPACITY]> {
+        if self.is_inline() {
+            unsafe { Some(&self.union.inline.data) }
+        } else {
+            None
+        }
+    }
     
     #[inline(always)]
     pub fn is_inline(&self) -> bool {
@@ -29,8 +41,27 @@ impl $name {
         }
     }
     
+    #[cfg(target_endian = "big")]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len_portable()
+    }
+
+    /// on little endian the inline length byte is the most significant byte of `heap.len`,
+    /// so the length can be selected with a mask instead of a branch.
+    #[cfg(target_endian = "little")]
     #[inline(always)]
     pub fn len(&self) -> usize {
+        let word = unsafe { self.union.heap.len };
+        let inline = word >> (usize::BITS - 1);
+        let mask = 0usize.wrapping_sub(inline);
+        let inline_len = (word >> (usize::BITS - 8)) & LEN_MASK as usize;
+        (word & !mask) | (inline_len & mask)
+    }
+
+    #[cfg_attr(target_endian = "little", allow(dead_code))]
+    #[inline(always)]
+    fn len_portable(&self) -> usize {
         unsafe {
             if self.is_inline() {
                 (self.union.inline.len & LEN_MASK) as usize
@@ -39,6 +70,22 @@ impl $name {
             }
         }
     }
+    /// pointer to the first byte, either inside `self` or on the heap.
+    ///
+    /// while inline, the bytes live in the struct itself, so the pointer is invalidated
+    /// whenever `self` is moved or mutated. Once heap-backed, it stays valid until
+    /// the allocation is grown, shrunk or dropped.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const u8 {
+        unsafe {
+            if self.is_inline() {
+                &self.union.inline.data as *const u8
+            } else {
+                self.union.heap.ptr
+            }
+        }
+    }
+    /// mutable pointer to the first byte, see `as_ptr` for when it is invalidated.
     #[inline(always)]
     pub fn as_mut_ptr(&mut self) -> *mut u8 {
         unsafe {
@@ -49,6 +96,29 @@ impl $name {
             }
         }
     }
+    /// the size of the heap allocation in bytes, `0` while inline.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        self.heap_capacity().unwrap_or(0)
+    }
+    /// the total memory used, `size_of::<Self>()` plus the heap allocation.
+    #[inline]
+    pub fn size_of_stored(&self) -> usize {
+        core::mem::size_of::<Self>() + self.allocated_bytes()
+    }
+    /// view as `&str` if the bytes are valid UTF-8.
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_slice())
+    }
+    /// view as `&str` without checking.
+    ///
+    /// # Safety
+    /// the bytes must be valid UTF-8.
+    #[inline]
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        core::str::from_utf8_unchecked(self.as_slice())
+    }
     #[inline(always)]
     pub fn as_slice(&self) -> &[u8] {
         let len = self.len();
@@ -93,6 +163,7 @@ impl $name {
     }
     pub unsafe fn from_inline(mut inline: Inline) -> Self {
         assert!(inline.len as usize <= INLINE_CAPACITY);
+        inline.data[inline.len as usize ..].fill(0);
         inline.len |= IS_INLINE; // set inline bit
         $name {
             union: $union { inline: inline },
@@ -139,17 +210,51 @@ impl PartialEq<[u8]> for $name {
         self.as_slice() == rhs
     }
 }
+impl<'a> PartialEq<&'a [u8]> for $name {
+    #[inline(always)]
+    fn eq(&self, rhs: &&'a [u8]) -> bool {
+        self.as_slice() == *rhs
+    }
+}
+impl PartialEq<alloc::vec::Vec<u8>> for $name {
+    #[inline(always)]
+    fn eq(&self, rhs: &alloc::vec::Vec<u8>) -> bool {
+        self.as_slice() == rhs.as_slice()
+    }
+}
+impl PartialEq<$name> for [u8] {
+    #[inline(always)]
+    fn eq(&self, rhs: &$name) -> bool {
+        self == rhs.as_slice()
+    }
+}
+impl<'a> PartialEq<$name> for &'a [u8] {
+    #[inline(always)]
+    fn eq(&self, rhs: &$name) -> bool {
+        *self == rhs.as_slice()
+    }
+}
+impl PartialEq<$name> for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn eq(&self, rhs: &$name) -> bool {
+        self.as_slice() == rhs.as_slice()
+    }
+}
 impl PartialEq for $name {
     fn eq(&self, rhs: &Self) -> bool {
         self.as_slice().eq(rhs.as_slice())
     }
 }
 impl Eq for $name {}
+/// hashes exactly like the `[u8]` it contains.
+///
+/// Note that this differs from the hash of a string type with the same content.
 impl core::hash::Hash for $name {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_slice().hash(state);
     }
 }
+/// orders lexicographically by bytes, like `[u8]`, consistent with `Eq` and `Hash`.
 impl cmp::PartialOrd for $name {
     #[inline(always)]
     fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
@@ -245,14 +350,177 @@ impl $name {
         }
     }
     
+    /// validate and copy borrowed bytes, without going through a `Vec<u8>`.
+    #[inline]
+    pub fn try_from_utf8(bytes: &[u8]) -> Result<$name, core::str::Utf8Error> {
+        core::str::from_utf8(bytes).map($name::from)
+    }
+
     #[inline(always)]
     pub fn as_mut_str(&mut self) -> &mut str {
         unsafe {
             str::from_utf8_unchecked_mut(self.bytes.as_mut_slice())
         }
     }
-    
-    
+
+    /// pointer to the first byte.
+    ///
+    /// not stable across moves or mutation while inline, see `as_ptr` of the bytes type.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.bytes.as_ptr()
+    }
+
+    /// mutable pointer to the first byte, see `as_ptr`.
+    ///
+    /// the bytes must remain valid UTF-8.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.bytes.as_mut_ptr()
+    }
+
+    /// the size of the heap allocation in bytes, `0` while inline.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        self.bytes.allocated_bytes()
+    }
+
+    /// the total memory used, `size_of::<Self>()` plus the heap allocation.
+    #[inline(always)]
+    pub fn size_of_stored(&self) -> usize {
+        self.bytes.size_of_stored()
+    }
+
+    #[inline]
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        self.as_str().chars()
+    }
+
+    #[inline]
+    pub fn char_indices(&self) -> core::str::CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> core::str::Bytes<'_> {
+        self.as_str().bytes()
+    }
+
+    /// the chars in reverse order.
+    #[inline]
+    pub fn chars_rev(&self) -> core::iter::Rev<core::str::Chars<'_>> {
+        self.as_str().chars().rev()
+    }
+
+    #[inline]
+    pub fn first_char(&self) -> Option<char> {
+        self.as_str().chars().next()
+    }
+
+    #[inline]
+    pub fn last_char(&self) -> Option<char> {
+        self.as_str().chars().next_back()
+    }
+
+    /// split at the first `delim` into two owned strings, see `str::split_once`.
+    ///
+    /// short halves are stored inline.
+    pub fn split_once_owned(&self, delim: char) -> Option<($name, $name)> {
+        let (a, b) = self.as_str().split_once(delim)?;
+        Some(($name::from(a), $name::from(b)))
+    }
+
+    /// escape the string like `str::escape_default`.
+    #[must_use]
+    pub fn escape_default(&self) -> $name {
+        let mut s = crate::IString::with_capacity(self.len());
+        s.extend(self.as_str().escape_default());
+        s.into()
+    }
+
+    /// escape the string like `str::escape_debug`.
+    #[must_use]
+    pub fn escape_debug(&self) -> $name {
+        let mut s = crate::IString::with_capacity(self.len());
+        s.extend(self.as_str().escape_debug());
+        s.into()
+    }
+
+    /// the char at char position `char_index`, in O(n).
+    #[inline]
+    pub fn char_at(&self, char_index: usize) -> Option<char> {
+        self.as_str().chars().nth(char_index)
+    }
+
+    /// the byte position of the char at char position `char_index`, in O(n).
+    ///
+    /// returns `len()` for `char_index == chars().count()` and `None` past that.
+    #[inline]
+    pub fn byte_offset_of_char(&self, char_index: usize) -> Option<usize> {
+        let mut indices = self.as_str().char_indices().map(|(i, _)| i).chain(core::iter::once(self.len()));
+        indices.nth(char_index)
+    }
+
+    /// split into two string slices at byte position `mid`.
+    ///
+    /// panics if `mid` is not on a char boundary or past the end.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (&str, &str) {
+        self.as_str().split_at(mid)
+    }
+
+    /// convert all ASCII characters to upper case, in place.
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.bytes.as_mut_slice().make_ascii_uppercase();
+    }
+
+    /// convert all ASCII characters to lower case, in place.
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.bytes.as_mut_slice().make_ascii_lowercase();
+    }
+
+    /// compare with `other`, ignoring the case of ASCII letters, see `str::eq_ignore_ascii_case`.
+    ///
+    /// `&IString` and the other string types deref to `&str`, so they can be passed directly.
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// call `f` on each ASCII byte, for custom in-place ASCII transforms.
+    ///
+    /// non-ASCII bytes are skipped. panics if `f` turns an ASCII byte into a non-ASCII byte,
+    /// in which case that byte is left unchanged, so the string stays valid UTF-8.
+    pub fn for_each_ascii_byte_mut<F: FnMut(&mut u8)>(&mut self, mut f: F) {
+        for b in self.bytes.as_mut_slice() {
+            if b.is_ascii() {
+                let mut c = *b;
+                f(&mut c);
+                assert!(c.is_ascii(), "for_each_ascii_byte_mut: {:#04x} is not ASCII", c);
+                *b = c;
+            }
+        }
+    }
+
+    /// returns a copy with all ASCII characters converted to upper case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> $name {
+        let mut s = self.clone();
+        s.make_ascii_uppercase();
+        s
+    }
+
+    /// returns a copy with all ASCII characters converted to lower case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> $name {
+        let mut s = self.clone();
+        s.make_ascii_lowercase();
+        s
+    }
 }
 impl $name {
     #[inline(always)]
@@ -279,13 +547,24 @@ impl ops::Deref for $name {
 impl fmt::Debug for $name {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        <str as fmt::Debug>::fmt(&*self, f)
+        if f.alternate() {
+            // `{:#?}` shows the storage details
+            f.debug_struct(stringify!($name))
+                .field("inline", &self.bytes.is_inline())
+                .field("len", &self.len())
+                .field("cap", &self.bytes.heap_capacity().unwrap_or($name::INLINE_CAPACITY))
+                .field("str", &self.as_str())
+                .finish()
+        } else {
+            <str as fmt::Debug>::fmt(&*self, f)
+        }
     }
 }
 impl fmt::Display for $name {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        <str as fmt::Display>::fmt(&*self, f)
+        // respects width, alignment, fill and precision
+        f.pad(self.as_str())
     }
 }
 
@@ -313,6 +592,9 @@ impl PartialEq for $name {
     }
 }
 impl Eq for $name {}
+/// hashes exactly like the `str` it contains, so it can be looked up by `&str` in a hash map.
+///
+/// Note that this differs from the hash of a bytes type with the same content.
 impl core::hash::Hash for $name {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
@@ -401,5 +683,13 @@ impl Borrow<str> for $name {
     }
 }
 
+#[cfg(feature="std")]
+impl AsRef<std::ffi::OsStr> for $name {
+    #[inline]
+    fn as_ref(&self) -> &std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+
     }
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+//! an `IString` with a precomputed hash.
+
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::Deref;
+use core::{fmt, str};
+
+use crate::IString;
+
+/// an immutable `IString` that stores its hash.
+///
+/// `Hash` writes only the cached `u64`, and `PartialEq` compares the hashes before the content.
+/// The hash is computed once, as `build.hash_one(s.as_str())`.
+#[derive(Clone)]
+pub struct HashedIString {
+    hash: u64,
+    string: IString,
+}
+
+impl HashedIString {
+    /// hash `string` with a hasher from `build`.
+    ///
+    /// equality compares the cached hashes first, so all values that are compared with each
+    /// other must be created with the same `build`. Equal strings hashed by different
+    /// hashers, e.g. two `RandomState`s, compare unequal.
+    #[inline]
+    pub fn new<B: BuildHasher>(string: IString, build: &B) -> HashedIString {
+        let hash = build.hash_one(string.as_str());
+        HashedIString { hash, string }
+    }
+
+    /// the cached hash.
+    #[inline]
+    pub fn hash_value(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> IString {
+        self.string
+    }
+}
+
+impl Deref for HashedIString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl AsRef<str> for HashedIString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl Hash for HashedIString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+impl PartialEq for HashedIString {
+    #[inline]
+    fn eq(&self, other: &HashedIString) -> bool {
+        self.hash == other.hash && self.string == other.string
+    }
+}
+impl Eq for HashedIString {}
+
+impl fmt::Debug for HashedIString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.string, f)
+    }
+}
+impl fmt::Display for HashedIString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.string, f)
+    }
+}
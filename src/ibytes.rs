@@ -1,11 +1,18 @@
 use alloc::vec::Vec;
+use alloc::collections::TryReserveError;
 use core::{ptr, mem, slice, convert, ops, cmp, fmt};
+use core::mem::MaybeUninit;
 use core::ops::{Index};
 use core::borrow::Borrow;
+use crate::{IString, FromUtf8Error};
 
 #[cfg(feature="ts")]
 use alloc::{borrow::ToOwned, string::String, format};
 
+// A set bit marks the inline variant. This means all-zero memory is *not* a valid empty
+// IBytes but a heap variant with a null pointer, so IBytes can't be `bytemuck::Zeroable`.
+// Flipping the bit would need every heap length access to mask out the MSB, which is the
+// last byte in memory on little endian and the first one on big endian, for no real gain.
 const IS_INLINE: u8 = 1 << 7;
 const LEN_MASK: u8 = !IS_INLINE;
 
@@ -54,11 +61,22 @@ pub struct Heap {
     pub cap:    usize
 }
 
+/// the owned representation, see `IBytes::into_inline_or_heap`.
 pub enum InlineOrHeap {
+    /// `len` does not include the inline flag.
     Inline(Inline),
+    /// the caller owns the allocation, see `IBytes::from_heap`.
     Heap(Heap)
 }
 
+/// the borrowed representation, see `IBytes::as_inline_or_heap`.
+#[derive(Copy, Clone)]
+pub enum InlineOrHeapRef<'a> {
+    /// the inline bytes
+    Inline(&'a [u8]),
+    Heap(&'a Heap)
+}
+
 pub union IBytesUnion {
     inline: Inline,
     heap:   Heap
@@ -69,9 +87,62 @@ pub struct IBytes {
     union: IBytesUnion,
 }
 
+// SAFETY: the heap pointer is uniquely owned, like the buffer of a `Vec<u8>`,
+// and only mutated through `&mut self`. `IString` inherits both.
 unsafe impl Send for IBytes {}
 unsafe impl Sync for IBytes {}
 
+#[cfg(feature="rkyv")]
+mod rkyv_impl {
+    use rkyv::{
+        vec::{ArchivedVec, VecResolver},
+        ser::{Allocator, Writer},
+        Archive, Deserialize, Serialize, Place
+    };
+    use rancor::Fallible;
+    use super::IBytes;
+
+    /// archived as `ArchivedVec<u8>`, so short values are not stored inline in the archive.
+    ///
+    /// rkyv has no inline representation for bytes, and a custom one would need its own
+    /// validation and no longer match the archive of a `Vec<u8>`.
+    impl Archive for IBytes {
+        type Archived = ArchivedVec<u8>;
+        type Resolver = VecResolver;
+
+        #[inline]
+        fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+            ArchivedVec::resolve_from_slice(self.as_slice(), resolver, out);
+        }
+    }
+
+    impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for IBytes {
+        #[inline]
+        fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+            ArchivedVec::<u8>::serialize_from_slice(self.as_slice(), serializer)
+        }
+    }
+    impl<D: Fallible + ?Sized> Deserialize<IBytes, D> for ArchivedVec<u8> {
+        #[inline]
+        fn deserialize(&self, _: &mut D) -> Result<IBytes, D::Error> {
+            Ok(self.as_slice().into())
+        }
+    }
+    impl PartialEq<IBytes> for ArchivedVec<u8> {
+        #[inline]
+        fn eq(&self, other: &IBytes) -> bool {
+            PartialEq::eq(self.as_slice(), other.as_slice())
+        }
+    }
+
+    impl PartialEq<ArchivedVec<u8>> for IBytes {
+        #[inline]
+        fn eq(&self, other: &ArchivedVec<u8>) -> bool {
+            PartialEq::eq(other.as_slice(), self.as_slice())
+        }
+    }
+}
+
 #[test]
 fn test_layout() {
     let s = IBytesUnion { inline: Inline { data: [0; INLINE_CAPACITY], len: IS_INLINE } };
@@ -79,6 +150,45 @@ fn test_layout() {
     assert_eq!(heap.len, MAX_CAPACITY + 1);
 }
 
+#[test]
+fn test_len_byte_is_msb() {
+    // on either endianness the inline len byte overlaps the most significant byte of heap.len
+    let s = IBytesUnion { inline: Inline { data: [0xff; INLINE_CAPACITY], len: IS_INLINE | 3 } };
+    let heap = unsafe { s.heap };
+    assert_eq!(heap.len.to_be_bytes()[0], IS_INLINE | 3);
+}
+
+#[test]
+fn test_zeroed_is_heap() {
+    let s = IBytesUnion { inline: Inline { data: [0; INLINE_CAPACITY], len: 0 } };
+    let heap = unsafe { s.heap };
+    assert_eq!(heap.len, 0);
+    assert!(heap.ptr.is_null());
+}
+
+#[test]
+fn test_len() {
+    let mut b = IBytes::new();
+    for i in 0 .. 200 {
+        assert_eq!(b.len(), i);
+        assert_eq!(b.len(), b.len_portable());
+        b.push(i as u8);
+    }
+    b.truncate(INLINE_CAPACITY);
+    b.shrink();
+    assert!(b.is_inline());
+    assert_eq!(b.len(), b.len_portable());
+}
+
+#[cfg(feature="bytemuck")]
+unsafe impl bytemuck::Zeroable for Inline {}
+
+// the single place that decides between the inline and the heap representation
+#[inline(always)]
+pub(crate) const fn fits_inline(len: usize) -> bool {
+    len <= INLINE_CAPACITY
+}
+
 #[inline]
 fn vec_into_raw_parts(mut s: Vec<u8>) -> (*mut u8, usize, usize) {
     let len = s.len();
@@ -92,6 +202,7 @@ define_common_bytes!(IBytes, IBytesUnion);
 
 impl IBytes {
     #[inline]
+    #[must_use]
     pub fn new() -> IBytes {
         IBytes {
             union: IBytesUnion {
@@ -99,11 +210,94 @@ impl IBytes {
             },
         }
     }
+    /// `(ptr, len, cap)` of either variant.
+    ///
+    /// when inline, `ptr` points into `self` and `cap` is `INLINE_CAPACITY`.
+    #[inline]
+    pub fn as_raw_parts(&self) -> (*const u8, usize, usize) {
+        (self.as_ptr(), self.len(), self.capacity())
+    }
+    /// decompose into the `(ptr, len, cap)` of a heap allocation, like `Vec::into_raw_parts`.
+    ///
+    /// moves inline bytes to the heap first. The parts can be turned back with `from_raw_parts_full`.
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut u8, usize, usize) {
+        vec_into_raw_parts(Vec::from(self))
+    }
+    /// take ownership of the parts of a `Vec<u8>`.
+    ///
+    /// # Safety
+    /// same requirements as `Vec::from_raw_parts`, and `cap` must be less than `2^63 - 1` (`2^31 - 1` on 32bit).
+    #[inline]
+    pub unsafe fn from_raw_parts_full(ptr: *mut u8, len: usize, cap: usize) -> IBytes {
+        debug_assert!(cap < MAX_CAPACITY);
+        IBytes::from(Vec::from_raw_parts(ptr, len, cap))
+    }
+    /// convert into an `IString` if the bytes are valid UTF-8, reusing the allocation.
     #[inline]
+    pub fn into_string(self) -> Result<IString, FromUtf8Error<IBytes>> {
+        IString::from_utf8(self)
+    }
+    /// deconstruct into the inline or heap part.
+    ///
+    /// In the heap case the caller takes over the allocation, for example by passing it to `from_heap`.
+    #[inline]
+    pub fn into_inline_or_heap(self) -> InlineOrHeap {
+        let this = mem::ManuallyDrop::new(self);
+        unsafe {
+            if this.is_inline() {
+                let mut inline = this.union.inline;
+                inline.len &= LEN_MASK;
+                InlineOrHeap::Inline(inline)
+            } else {
+                InlineOrHeap::Heap(this.union.heap)
+            }
+        }
+    }
+    /// view the inline bytes or the heap part.
+    #[inline]
+    pub fn as_inline_or_heap(&self) -> InlineOrHeapRef<'_> {
+        unsafe {
+            if self.is_inline() {
+                InlineOrHeapRef::Inline(self.as_slice())
+            } else {
+                InlineOrHeapRef::Heap(&self.union.heap)
+            }
+        }
+    }
+    /// create an inline IBytes in a const context, or `None` if `bytes` does not fit inline.
+    #[inline]
+    #[must_use]
+    pub const fn new_inline(bytes: &[u8]) -> Option<IBytes> {
+        if !fits_inline(bytes.len()) {
+            return None;
+        }
+        Some(IBytes::inline_from_slice(bytes))
+    }
+    // `bytes` must fit inline
+    pub(crate) const fn inline_from_slice(bytes: &[u8]) -> IBytes {
+        let len = bytes.len();
+        let mut data = [0; INLINE_CAPACITY];
+        let mut i = 0;
+        while i < len {
+            data[i] = bytes[i];
+            i += 1;
+        }
+        IBytes {
+            union: IBytesUnion {
+                inline: Inline { data, len: len as u8 | IS_INLINE }
+            },
+        }
+    }
+    /// create an empty IBytes that can hold at least `capacity` bytes.
+    ///
+    /// panics if `capacity` >= `2^63 - 1` (`2^31 - 1` on 32bit).
+    #[inline]
+    #[must_use]
     pub fn with_capacity(capacity: usize) -> IBytes {
         assert!(capacity < MAX_CAPACITY);
         
-        if capacity > INLINE_CAPACITY {
+        if !fits_inline(capacity) {
             let (ptr, len, cap) = vec_into_raw_parts(Vec::with_capacity(capacity));
             IBytes {
                 union: IBytesUnion {
@@ -122,10 +316,46 @@ impl IBytes {
             }
         }
     }
+    /// like `with_capacity`, but returns an error instead of panicking or aborting.
+    pub fn try_with_capacity(capacity: usize) -> Result<IBytes, TryReserveError> {
+        let mut b = IBytes::new();
+        b.try_reserve_exact(capacity)?;
+        Ok(b)
+    }
+    /// set the length to `new_len`.
+    ///
+    /// together with `spare_capacity_mut` this allows filling the buffer in place:
+    /// ```
+    /// # use istring::IBytes;
+    /// let mut b = IBytes::with_capacity(64);
+    /// let spare = b.spare_capacity_mut();
+    /// let n = 5; // e.g. the number of bytes returned by a read
+    /// for (dst, &src) in spare.iter_mut().zip(b"hello") {
+    ///     dst.write(src);
+    /// }
+    /// unsafe { b.set_len(n) };
+    /// assert_eq!(b, b"hello"[..]);
+    /// ```
+    ///
+    /// panics if `new_len` exceeds `capacity()`.
+    ///
+    /// # Safety
+    /// the bytes up to `new_len` must be initialized.
     #[inline(always)]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         assert!(new_len <= self.capacity());
+        self.set_len_unchecked(new_len);
+    }
+    /// like `set_len`, without checking the capacity.
+    ///
+    /// # Safety
+    /// `new_len` must not exceed `capacity()` and the bytes up to `new_len` must be initialized.
+    #[inline(always)]
+    pub unsafe fn set_len_unchecked(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
         if self.is_inline() {
+            // keep the unused inline bytes zeroed, including any written through `spare_capacity_mut`
+            self.union.inline.data[new_len ..].fill(0);
             self.union.inline.len = new_len as u8 | IS_INLINE;
         } else {
             self.union.heap.len = new_len;
@@ -139,6 +369,41 @@ impl IBytes {
             unsafe { self.union.heap.cap }
         }
     }
+    /// split into chunks of `N` bytes and the remainder, like `slice::as_chunks`.
+    ///
+    /// `N` must not be zero.
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[u8; N]], &[u8]) {
+        const { assert!(N != 0, "chunk size must not be zero") };
+        let bytes = self.as_slice();
+        let count = bytes.len() / N;
+        let (head, tail) = bytes.split_at(count * N);
+        // [u8; N] has the size N and the alignment of u8
+        let chunks = unsafe { slice::from_raw_parts(head.as_ptr().cast::<[u8; N]>(), count) };
+        (chunks, tail)
+    }
+    /// the unused capacity past `len()`, which may be uninitialized.
+    ///
+    /// write into it and call `set_len` to make the bytes part of `self`.
+    /// While inline, `set_len` zeroes the bytes past the new length again,
+    /// so `inline_bytes` keeps its zeroed tail.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        let len = self.len();
+        let cap = self.capacity();
+        unsafe {
+            slice::from_raw_parts_mut(self.as_mut_ptr().add(len) as *mut MaybeUninit<u8>, cap - len)
+        }
+    }
+    /// the capacity of the heap allocation, or `None` if inline.
+    #[inline]
+    pub fn heap_capacity(&self) -> Option<usize> {
+        if self.is_inline() {
+            None
+        } else {
+            unsafe { Some(self.union.heap.cap) }
+        }
+    }
     /// un-inline the string and expand the capacity to `cap`.
     ///
     /// does nothing if it isn't inlined.
@@ -163,74 +428,210 @@ impl IBytes {
     /// if the strings fits inline, make it inline,
     /// otherwhise shrink the capacity to the `self.len()`.
     pub fn shrink(&mut self) {
+        if self.is_inline() {
+            return;
+        }
         let len = self.len();
-        if len <= INLINE_CAPACITY {
+        if fits_inline(len) {
             unsafe {
                 let heap = self.union.heap;
                 self.union.inline.len = len as u8 | IS_INLINE;
                 ptr::copy_nonoverlapping(heap.ptr, self.union.inline.data.as_mut_ptr(), len);
+                self.union.inline.data[len ..].fill(0);
                 Vec::from_raw_parts(heap.ptr, len, heap.cap);
             }
         } else {
-            self.resize(len);
+            self.realloc_exact(len);
         }
     }
-    pub (crate) fn resize(&mut self, new_cap: usize) {
+    /// grow the heap capacity to at least `new_cap`, using the amortized growth of `Vec::reserve`.
+    pub (crate) fn realloc(&mut self, new_cap: usize) {
+        self.with_vec(|data| {
+            let len = data.len();
+            data.reserve(new_cap - len);
+        });
+    }
+    /// set the heap capacity to `new_cap`, as far as the allocator allows.
+    pub (crate) fn realloc_exact(&mut self, new_cap: usize) {
+        self.with_vec(|data| {
+            let len = data.len();
+            data.reserve_exact(new_cap - len);
+            data.shrink_to(new_cap);
+        });
+    }
+    fn with_vec(&mut self, f: impl FnOnce(&mut Vec<u8>)) {
         assert_eq!(self.is_inline(), false);
-        assert!(new_cap >= self.len());
         
         unsafe {
             let len = self.len();
             let mut data = Vec::from_raw_parts(self.union.heap.ptr, len, self.union.heap.cap);
             self.union.heap.ptr = ptr::null_mut();
 
-            data.reserve(new_cap - len);
+            f(&mut data);
             let (ptr, _, cap) = vec_into_raw_parts(data);
             self.union.heap.ptr = ptr;
             self.union.heap.cap = cap;
         }
     }
+    /// reserve capacity for at least `additional` more bytes.
+    ///
+    /// does nothing if the capacity is already sufficient.
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
-        let new_cap = self.capacity() + additional;
+        let new_cap = self.len().checked_add(additional).expect("IBytes::reserve: capacity overflow");
+        if new_cap <= self.capacity() {
+            return;
+        }
         if self.is_inline() {
-            if new_cap > INLINE_CAPACITY {
-                self.move_to_heap(new_cap);
-            }
+            self.move_to_heap(new_cap);
         } else {
-            self.resize(new_cap);
+            self.realloc(new_cap);
         }
     }
     
+    /// grow the capacity to exactly `new_cap`, without rounding it up.
+    ///
+    /// for custom growth strategies. does nothing if the capacity is already at least `new_cap`.
+    pub fn grow_to(&mut self, new_cap: usize) {
+        if new_cap <= self.capacity() {
+            return;
+        }
+        if self.is_inline() {
+            self.move_to_heap(new_cap);
+        } else {
+            self.realloc_exact(new_cap);
+        }
+    }
+    /// reserve capacity for exactly `additional` more bytes, without rounding up.
+    ///
+    /// does nothing if the capacity is already sufficient.
     #[inline]
     pub fn reserve_exact(&mut self, additional: usize) {
-        let new_cap = self.capacity() + additional;
+        let new_cap = self.len().checked_add(additional).expect("IBytes::reserve_exact: capacity overflow");
+        if new_cap <= self.capacity() {
+            return;
+        }
         if self.is_inline() {
             self.move_to_heap(new_cap);
         } else {
-            self.resize(new_cap);
+            self.realloc_exact(new_cap);
+        }
+    }
+    /// try to reserve capacity for at least `additional` more bytes.
+    ///
+    /// on failure `self` is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_grow(additional, false)
+    }
+    /// try to reserve capacity for exactly `additional` more bytes.
+    ///
+    /// on failure `self` is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.try_grow(additional, true)
+    }
+    fn try_grow(&mut self, additional: usize, exact: bool) -> Result<(), TryReserveError> {
+        let len = self.len();
+        if self.is_inline() {
+            if additional <= INLINE_CAPACITY - len {
+                return Ok(());
+            }
+            // an overflowing length saturates, which the allocation then rejects
+            let cap = len.saturating_add(additional);
+            let mut data = Vec::new();
+            if exact {
+                data.try_reserve_exact(cap)?;
+            } else {
+                data.try_reserve(cap)?;
+            }
+            data.extend_from_slice(self.as_slice());
+            *self = IBytes::from(data);
+            Ok(())
+        } else {
+            let mut result = Ok(());
+            self.with_vec(|data| {
+                result = if exact {
+                    data.try_reserve_exact(additional)
+                } else {
+                    data.try_reserve(additional)
+                };
+            });
+            result
         }
     }
     #[inline]
     pub fn push(&mut self, byte: u8) {
         self.extend_from_slice(&[byte]);
     }
+    /// remove the last byte and return it.
+    #[inline]
+    pub fn pop(&mut self) -> Option<u8> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let byte = self.as_slice()[len - 1];
+        unsafe { self.set_len(len - 1) }
+        Some(byte)
+    }
+    /// shorten to `new_len` bytes.
+    ///
+    /// does nothing if `new_len` is greater than the current length.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            unsafe { self.set_len(new_len) }
+        }
+    }
+    /// remove all bytes, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+    /// truncate or extend to `new_len` bytes, filling new bytes with `value`, like `Vec::resize`.
+    ///
+    /// moves to the heap if `new_len` does not fit inline.
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+        self.reserve(new_len - len);
+        unsafe {
+            ptr::write_bytes(self.as_mut_ptr().add(len), value, new_len - len);
+            self.set_len_unchecked(new_len);
+        }
+    }
+    /// overwrite all bytes with `value`.
+    #[inline]
+    pub fn fill(&mut self, value: u8) {
+        self.as_mut_slice().fill(value);
+    }
     pub fn extend_from_slice(&mut self, bytes: &[u8]) {
         let old_len = self.len();
         let new_len = old_len + bytes.len();
-        if self.is_inline() {
-            if new_len > INLINE_CAPACITY {
+
+        // branch on the variant once and write the length field directly
+        unsafe {
+            if self.is_inline() {
+                if fits_inline(new_len) {
+                    let inline = &mut self.union.inline;
+                    ptr::copy_nonoverlapping(bytes.as_ptr(), inline.data.as_mut_ptr().add(old_len), bytes.len());
+                    inline.len = new_len as u8 | IS_INLINE;
+                    return;
+                }
                 self.move_to_heap(new_len.next_power_of_two());
+            } else {
+                let cap = self.union.heap.cap;
+                if new_len > cap {
+                    // grow geometrically relative to the current capacity, like Vec
+                    self.realloc(cmp::max(new_len.next_power_of_two(), cap.saturating_mul(2)));
+                }
             }
-        } else {
-            if new_len > self.capacity() {
-                self.resize(new_len.next_power_of_two());
-            }
-        }
 
-        unsafe {
-            ptr::copy_nonoverlapping(bytes.as_ptr(), self.as_mut_ptr().offset(old_len as isize), bytes.len());
-            self.set_len(new_len);
+            let heap = &mut self.union.heap;
+            ptr::copy_nonoverlapping(bytes.as_ptr(), heap.ptr.add(old_len), bytes.len());
+            heap.len = new_len;
         }
     }
 }
@@ -249,7 +650,7 @@ impl Drop for IBytes {
 impl<'a> convert::From<&'a [u8]> for IBytes {
     #[inline]
     fn from(s: &'a [u8]) -> IBytes {
-        if s.len() > INLINE_CAPACITY {
+        if !fits_inline(s.len()) {
             let (ptr, len, cap) = vec_into_raw_parts(Vec::from(s));
             let heap = Heap {
                 ptr,
@@ -274,6 +675,7 @@ impl<'a> convert::From<&'a str> for IBytes {
         IBytes::from(s.as_bytes())
     }
 }
+/// reuses the allocation of `s`, unless it has none.
 impl convert::From<Vec<u8>> for IBytes {
     #[inline]
     fn from(s: Vec<u8>) -> IBytes {
@@ -299,24 +701,58 @@ impl convert::From<alloc::string::String> for IBytes {
         IBytes::from(s.into_bytes())
     }
 }
-impl convert::Into<Vec<u8>> for IBytes {
+/// hands over the heap allocation, inline bytes are moved to the heap first.
+impl convert::From<IBytes> for Vec<u8> {
     #[inline]
-    fn into(mut self) -> Vec<u8> {
-        if self.is_inline() {
-            let len = self.len();
-            self.move_to_heap(len);
+    fn from(mut b: IBytes) -> Vec<u8> {
+        if b.is_inline() {
+            let len = b.len();
+            b.move_to_heap(len);
         }
         
         unsafe {
-            let s = Vec::from_raw_parts(self.union.heap.ptr, self.union.heap.len, self.union.heap.cap);
+            let s = Vec::from_raw_parts(b.union.heap.ptr, b.union.heap.len, b.union.heap.cap);
 
             // the IBytes must not drop
-            mem::forget(self);
+            mem::forget(b);
             s
         }
     }
 }
 
+impl Extend<u8> for IBytes {
+    #[inline]
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        let iterator = iter.into_iter();
+        let (lower_bound, _) = iterator.size_hint();
+        self.reserve(lower_bound);
+        for byte in iterator {
+            self.push(byte);
+        }
+    }
+}
+impl<'a> Extend<&'a u8> for IBytes {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+impl<'a> Extend<&'a [u8]> for IBytes {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+        for s in iter {
+            self.extend_from_slice(s);
+        }
+    }
+}
+impl FromIterator<u8> for IBytes {
+    fn from_iter<T>(iter: T) -> Self where T: IntoIterator<Item=u8> {
+        let mut b = IBytes::new();
+        b.extend(iter);
+        b
+    }
+}
+
 impl Clone for IBytes {
     #[inline]
     fn clone(&self) -> IBytes {
@@ -334,6 +770,20 @@ impl Clone for IBytes {
             }
         }
     }
+    /// reuses the heap allocation of `self` if it is large enough.
+    #[inline]
+    fn clone_from(&mut self, source: &IBytes) {
+        let len = source.len();
+        if !self.is_inline() && self.capacity() >= len {
+            unsafe {
+                let heap = &mut self.union.heap;
+                ptr::copy_nonoverlapping(source.as_ptr(), heap.ptr, len);
+                heap.len = len;
+            }
+        } else {
+            *self = source.clone();
+        }
+    }
 }
 
 #[cfg(feature="size")]
@@ -1,48 +1,350 @@
-use core::{fmt, str, convert};
+use core::{fmt, str, slice, ptr, mem, convert};
 use core::clone::Clone;
 use core::iter::{FromIterator, IntoIterator, Extend};
-use core::ops::{self, Index, Add, AddAssign};
+use core::ops::{self, Index, Add, AddAssign, Bound, RangeBounds};
 use core::borrow::Borrow;
 use alloc::{string::String, vec::Vec};
 use alloc::borrow::Cow;
+use alloc::collections::TryReserveError;
 
 #[cfg(feature="ts")]
 use alloc::{borrow::ToOwned, format};
 
-use crate::ibytes::IBytes;
-use crate::FromUtf8Error;
+use crate::ibytes::{IBytes, Heap, InlineOrHeap, InlineOrHeapRef, fits_inline};
+use crate::{FromUtf8Error, FromUtf16Error};
 
-#[derive(Clone)]
 #[cfg_attr(feature="size", derive(datasize::DataSize))]
 #[cfg_attr(feature="ts", derive(ts_rs::TS), ts(type="String"))]
 pub struct IString {
     pub (crate) bytes: IBytes,
 }
 
+#[cfg(feature="rkyv")]
+mod rkyv_impl {
+    use rkyv::{
+        string::ArchivedString,
+        Archive, Deserialize, DeserializeUnsized, Serialize, SerializeUnsized, Place
+    };
+    use rancor::{Fallible, Source};
+    use super::IString;
+
+    /// archived as `ArchivedString`, which stores strings of up to 8 bytes inline.
+    impl Archive for IString {
+        type Archived = rkyv::string::ArchivedString;
+        type Resolver = rkyv::string::StringResolver;
+
+        #[inline]
+        fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+            rkyv::string::ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+        }
+    }
+
+    impl<S: Fallible + ?Sized> Serialize<S> for IString
+    where
+        str: SerializeUnsized<S>,
+        S::Error: Source
+    {
+        #[inline]
+        fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+            ArchivedString::serialize_from_str(self.as_str(), serializer)
+        }
+    }
+    impl<D: Fallible + ?Sized> Deserialize<IString, D> for ArchivedString
+    where
+        str: DeserializeUnsized<str, D>,
+    {
+        #[inline]
+        fn deserialize(&self, _: &mut D) -> Result<IString, D::Error> {
+            Ok(self.as_str().into())
+        }
+    }
+    impl PartialEq<IString> for ArchivedString {
+        #[inline]
+        fn eq(&self, other: &IString) -> bool {
+            PartialEq::eq(self.as_str(), other.as_str())
+        }
+    }
+    
+    impl PartialEq<ArchivedString> for IString {
+        #[inline]
+        fn eq(&self, other: &ArchivedString) -> bool {
+            PartialEq::eq(other.as_str(), self.as_str())
+        }
+    }
+}
+
+#[cfg(feature="rayon")]
+mod rayon_impl {
+    use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+    use alloc::vec::Vec;
+    use super::IString;
+
+    // the per-thread segments are joined at the end with a single reservation
+    fn join_segments(s: &mut IString, segments: Vec<IString>) {
+        let len = segments.iter().map(|s| s.len()).sum();
+        s.reserve(len);
+        for segment in segments {
+            s.push_str(&segment);
+        }
+    }
+
+    impl ParallelExtend<char> for IString {
+        fn par_extend<I: IntoParallelIterator<Item=char>>(&mut self, par_iter: I) {
+            let segments = par_iter.into_par_iter()
+                .fold(IString::new, |mut s, c| { s.push(c); s })
+                .collect();
+            join_segments(self, segments);
+        }
+    }
+    impl ParallelExtend<IString> for IString {
+        fn par_extend<I: IntoParallelIterator<Item=IString>>(&mut self, par_iter: I) {
+            let segments = par_iter.into_par_iter()
+                .fold(IString::new, |mut s, t| { s.push_str(&t); s })
+                .collect();
+            join_segments(self, segments);
+        }
+    }
+    impl FromParallelIterator<char> for IString {
+        fn from_par_iter<I: IntoParallelIterator<Item=char>>(par_iter: I) -> Self {
+            let mut s = IString::new();
+            s.par_extend(par_iter);
+            s
+        }
+    }
+    impl FromParallelIterator<IString> for IString {
+        fn from_par_iter<I: IntoParallelIterator<Item=IString>>(par_iter: I) -> Self {
+            let mut s = IString::new();
+            s.par_extend(par_iter);
+            s
+        }
+    }
+}
+
+/// resolve `range` into `start .. end` for a string of length `len`.
+///
+/// panics if the range is out of bounds.
+fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).expect("range start overflows"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).expect("range end overflows"),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start is greater than range end");
+    assert!(end <= len, "range end is out of bounds");
+    (start, end)
+}
+
+// length of a UTF-8 sequence, given its first byte
+#[inline]
+fn utf8_char_width(first: u8) -> usize {
+    match first {
+        0x00 ..= 0x7f => 1,
+        0x80 ..= 0xdf => 2,
+        0xe0 ..= 0xef => 3,
+        _ => 4,
+    }
+}
+
+/// iterator returned by `IString::extract_if`.
+///
+/// the string always holds the chars kept so far. On drop, the chars that have not
+/// been visited yet are appended.
+pub struct ExtractIf<'a, F: FnMut(char) -> bool> {
+    string: &'a mut IString,
+    // copy of the content if the string was inline, the heap buffer is read in place otherwise
+    inline: Option<[u8; IBytes::INLINE_CAPACITY]>,
+    read: usize,
+    len: usize,
+    pred: F,
+}
+impl<'a, F: FnMut(char) -> bool> ExtractIf<'a, F> {
+    #[inline]
+    fn src(&self) -> *const u8 {
+        match self.inline {
+            Some(ref data) => data.as_ptr(),
+            None => self.string.as_ptr(),
+        }
+    }
+    // append `count` bytes starting at `read` to the kept chars
+    #[inline]
+    fn keep(&mut self, read: usize, count: usize) {
+        let src = self.src();
+        let write = self.string.len();
+        unsafe {
+            // may overlap when reading from the heap buffer, `write <= read` holds
+            ptr::copy(src.add(read), self.string.bytes.as_mut_ptr().add(write), count);
+            self.string.set_len_unchecked(write + count);
+        }
+    }
+}
+impl<'a, F: FnMut(char) -> bool> Iterator for ExtractIf<'a, F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.read < self.len {
+            let read = self.read;
+            let ch = unsafe {
+                let src = self.src().add(read);
+                let width = utf8_char_width(*src);
+                let bytes = slice::from_raw_parts(src, width);
+                str::from_utf8_unchecked(bytes).chars().next().unwrap()
+            };
+            // only advance once the predicate returned, so a panic keeps `ch`
+            let remove = (self.pred)(ch);
+            self.read += ch.len_utf8();
+            if remove {
+                return Some(ch);
+            }
+            self.keep(read, ch.len_utf8());
+        }
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.read))
+    }
+}
+impl<'a, F: FnMut(char) -> bool> Drop for ExtractIf<'a, F> {
+    fn drop(&mut self) {
+        if self.read < self.len {
+            self.keep(self.read, self.len - self.read);
+            self.read = self.len;
+        }
+    }
+}
 
 impl IString {
+    /// the maximum length of a string that can be stored without a heap allocation.
+    pub const INLINE_CAPACITY: usize = IBytes::INLINE_CAPACITY;
+
     #[inline]
+    #[must_use]
     pub fn new() -> IString {
         IString {
             bytes: IBytes::new()
         }
     }
+    /// create an inline string in a const context, or `None` if `s` does not fit inline.
+    ///
+    /// see also the `istring!` macro.
+    #[inline]
+    #[must_use]
+    pub const fn new_inline(s: &str) -> Option<IString> {
+        if !fits_inline(s.len()) {
+            return None;
+        }
+        Some(IString { bytes: IBytes::inline_from_slice(s.as_bytes()) })
+    }
+    /// create an empty string that can hold at least `capacity` bytes.
+    ///
+    /// panics if `capacity` >= `2^63 - 1` (`2^31 - 1` on 32bit).
     #[inline]
+    #[must_use]
     pub fn with_capacity(capacity: usize) -> IString {
         IString {
             bytes: IBytes::with_capacity(capacity)
         }
     }
+    /// like `with_capacity`, but returns an error instead of panicking or aborting.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<IString, TryReserveError> {
+        Ok(IString {
+            bytes: IBytes::try_with_capacity(capacity)?
+        })
+    }
     #[inline(always)]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         self.bytes.set_len(new_len);
     }
+
+    /// like `set_len`, without checking the capacity.
+    ///
+    /// # Safety
+    /// `new_len` must not exceed `capacity()`, and the first `new_len` bytes must be
+    /// initialized and form valid UTF-8.
+    #[inline(always)]
+    pub unsafe fn set_len_unchecked(&mut self, new_len: usize) {
+        self.bytes.set_len_unchecked(new_len);
+    }
+
+    /// append `bytes` without checking the capacity or UTF-8 validity.
+    ///
+    /// # Safety
+    /// - `len() + bytes.len()` must not exceed `capacity()`, for example by calling `reserve` first.
+    /// - the existing content followed by `bytes` must be valid UTF-8.
+    ///   `bytes` may end in the middle of a char, as long as the rest is pushed before
+    ///   the string is used as a `str` again.
+    #[inline]
+    pub unsafe fn push_bytes_unchecked(&mut self, bytes: &[u8]) {
+        let len = self.len();
+        ptr::copy_nonoverlapping(bytes.as_ptr(), self.bytes.as_mut_ptr().add(len), bytes.len());
+        self.bytes.set_len_unchecked(len + bytes.len());
+    }
     
     #[inline(always)]
     pub fn capacity(&self) -> usize {
         self.bytes.capacity()
     }
     
+    #[inline(always)]
+    pub fn is_inline(&self) -> bool {
+        self.bytes.is_inline()
+    }
+    
+    /// `(ptr, len, cap)` of either variant, see `IBytes::as_raw_parts`.
+    #[inline]
+    pub fn as_raw_parts(&self) -> (*const u8, usize, usize) {
+        self.bytes.as_raw_parts()
+    }
+
+    /// decompose into the `(ptr, len, cap)` of a heap allocation, see `IBytes::into_raw_parts`.
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut u8, usize, usize) {
+        self.bytes.into_raw_parts()
+    }
+
+    /// take ownership of the parts of a `String`.
+    ///
+    /// # Safety
+    /// same requirements as `String::from_raw_parts`, and `cap` must be less than `2^63 - 1` (`2^31 - 1` on 32bit).
+    #[inline]
+    pub unsafe fn from_raw_parts_full(ptr: *mut u8, len: usize, cap: usize) -> IString {
+        IString {
+            bytes: IBytes::from_raw_parts_full(ptr, len, cap)
+        }
+    }
+
+    /// deconstruct into the inline or heap part, see `IBytes::into_inline_or_heap`.
+    #[inline]
+    pub fn into_inline_or_heap(self) -> InlineOrHeap {
+        self.bytes.into_inline_or_heap()
+    }
+
+    /// view the inline bytes or the heap part.
+    #[inline]
+    pub fn as_inline_or_heap(&self) -> InlineOrHeapRef<'_> {
+        self.bytes.as_inline_or_heap()
+    }
+
+    /// the capacity of the heap allocation, or `None` if inline.
+    #[inline(always)]
+    pub fn heap_capacity(&self) -> Option<usize> {
+        self.bytes.heap_capacity()
+    }
+    
+    /// the complete inline buffer, or `None` if not inline.
+    ///
+    /// bytes past `len()` are guaranteed to be zero.
+    #[inline(always)]
+    pub fn inline_bytes(&self) -> Option<&[u8; IBytes::INLINE_CAPACITY]> {
+        self.bytes.inline_bytes()
+    }
+    
     /// un-inline the string and expand the capacity to `cap`.
     ///
     /// does nothing if it isn't inlined.
@@ -64,6 +366,35 @@ impl IString {
         self.bytes.extend_from_slice(s.as_bytes());
     }
     
+    /// take over the allocation of `s` without ever inlining it.
+    ///
+    /// The returned string is heap-backed, so the pointer to its bytes stays the same
+    /// as long as no operation needs to grow or shrink the allocation.
+    ///
+    /// # Safety
+    /// `s` must have a non-zero capacity, so that there is an allocation to take over.
+    #[inline]
+    pub unsafe fn from_string_unchecked(s: String) -> IString {
+        debug_assert!(s.capacity() != 0);
+        let mut s = mem::ManuallyDrop::new(s);
+        let heap = Heap {
+            ptr: s.as_mut_ptr(),
+            len: s.len(),
+            cap: s.capacity(),
+        };
+        IString {
+            bytes: IBytes::from_heap(heap)
+        }
+    }
+
+    /// move the contents to the heap if inline, see `from_string_unchecked`.
+    #[inline]
+    pub fn into_heap_backed(mut self) -> IString {
+        let len = self.len();
+        self.move_to_heap(len);
+        self
+    }
+
     #[inline(always)]
     pub unsafe fn from_raw_parts(buf: *mut u8, length: usize, capacity: usize) -> IString {
         String::from_raw_parts(buf, length, capacity).into()
@@ -78,6 +409,28 @@ impl IString {
     pub fn reserve_exact(&mut self, additional: usize) {
         self.bytes.reserve_exact(additional);
     }
+
+    /// grow the capacity to exactly `new_cap`, without rounding it up, see `IBytes::grow_to`.
+    #[inline]
+    pub fn grow_to(&mut self, new_cap: usize) {
+        self.bytes.grow_to(new_cap);
+    }
+    
+    /// try to reserve capacity for at least `additional` more bytes.
+    ///
+    /// on failure `self` is left unchanged.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.bytes.try_reserve(additional)
+    }
+
+    /// try to reserve capacity for exactly `additional` more bytes.
+    ///
+    /// on failure `self` is left unchanged.
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.bytes.try_reserve_exact(additional)
+    }
     
     #[inline]
     pub fn push(&mut self, ch: char) {
@@ -85,13 +438,377 @@ impl IString {
         self.push_str(ch.encode_utf8(&mut buf));
     }
     
+    /// insert `ch` before the char at `char_index`, or at the end if there are fewer chars.
+    pub fn insert_char(&mut self, char_index: usize, ch: char) {
+        let idx = self.char_indices().nth(char_index).map_or(self.len(), |(i, _)| i);
+        let mut buf = [0; 4];
+        let encoded = ch.encode_utf8(&mut buf).as_bytes();
+        let len = self.len();
+        let n = encoded.len();
+        self.reserve(n);
+        unsafe {
+            let ptr = self.bytes.as_mut_ptr();
+            ptr::copy(ptr.add(idx), ptr.add(idx + n), len - idx);
+            ptr::copy_nonoverlapping(encoded.as_ptr(), ptr.add(idx), n);
+            self.set_len(len + n);
+        }
+    }
+
+    /// shorten the string to `new_len` bytes.
+    ///
+    /// does nothing if `new_len` is greater than the current length.
+    /// panics if `new_len` does not lie on a char boundary.
     #[inline]
     pub fn truncate(&mut self, new_len: usize) {
         if new_len < self.len() {
+            assert!(self.is_char_boundary(new_len), "new_len is not on a char boundary");
             unsafe { self.set_len(new_len) }
         }
     }
 
+    /// append the formatted `args`.
+    ///
+    /// makes `write!` work without importing `fmt::Write`.
+    /// only fails if a formatting trait implementation returns an error.
+    ///
+    /// This shadows `fmt::Write::write_fmt`, so a `use core::fmt::Write;` that was only
+    /// needed for `write!` into an `IString` now triggers an `unused_imports` warning.
+    #[inline]
+    pub fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        fmt::Write::write_fmt(self, args)
+    }
+
+    /// append the formatted `args`.
+    ///
+    /// panics if a formatting trait implementation returns an error.
+    #[inline]
+    pub fn push_fmt(&mut self, args: fmt::Arguments) {
+        self.write_fmt(args).expect("a formatting trait implementation returned an error");
+    }
+
+    /// replace the contents with the formatted `args`, keeping the capacity.
+    ///
+    /// use with `format_args!` to reuse the buffer across many format operations.
+    pub fn format_into(&mut self, args: fmt::Arguments) {
+        self.bytes.clear();
+        self.push_fmt(args);
+    }
+
+    /// keep the first `char_count` characters.
+    ///
+    /// does nothing if the string has fewer characters.
+    pub fn truncate_chars(&mut self, char_count: usize) {
+        if let Some((idx, _)) = self.char_indices().nth(char_count) {
+            unsafe { self.set_len(idx) }
+        }
+    }
+
+    /// remove the last character and return it.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe { self.set_len(new_len) }
+        Some(ch)
+    }
+
+    /// remove the character at byte position `idx` and return it.
+    ///
+    /// panics if `idx` is not on a char boundary or out of bounds.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx ..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+        let next = idx + ch.len_utf8();
+        let len = self.len();
+        self.bytes.as_mut_slice().copy_within(next .., idx);
+        unsafe { self.set_len(len - (next - idx)) }
+        ch
+    }
+
+    /// the string with leading and trailing whitespace removed, without allocating.
+    #[inline]
+    #[must_use]
+    pub fn trimmed(&self) -> &str {
+        self.as_str().trim()
+    }
+
+    /// remove leading and trailing whitespace in place, reusing the buffer.
+    #[must_use]
+    pub fn into_trimmed(mut self) -> IString {
+        let trimmed = self.trimmed();
+        let start = trimmed.as_ptr() as usize - self.as_ptr() as usize;
+        let count = trimmed.len();
+        if start != 0 {
+            self.bytes.as_mut_slice().copy_within(start .. start + count, 0);
+        }
+        unsafe { self.set_len(count) }
+        self
+    }
+
+    /// replace every run of ASCII whitespace with a single space and remove it at both ends.
+    ///
+    /// works in place, without reallocating.
+    pub fn collapse_whitespace(&mut self) {
+        let bytes = self.bytes.as_mut_slice();
+        let mut len = 0;
+        let mut space = false;
+        for i in 0 .. bytes.len() {
+            let b = bytes[i];
+            if b.is_ascii_whitespace() {
+                space = len != 0;
+            } else {
+                if space {
+                    bytes[len] = b' ';
+                    len += 1;
+                    space = false;
+                }
+                bytes[len] = b;
+                len += 1;
+            }
+        }
+        // only ASCII bytes were removed, so this is still valid UTF-8
+        unsafe { self.set_len(len) }
+    }
+
+    /// replace every run of consecutive `ch` with a single `ch`.
+    ///
+    /// works in place, without reallocating.
+    pub fn dedup_char(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        let pat = ch.encode_utf8(&mut buf).as_bytes();
+        let bytes = self.bytes.as_mut_slice();
+        let mut read = 0;
+        let mut write = 0;
+        let mut prev = false;
+        // `pat` starts with a leading byte, so it can only match at a char boundary
+        while read < bytes.len() {
+            if bytes[read ..].starts_with(pat) {
+                if !prev {
+                    bytes.copy_within(read .. read + pat.len(), write);
+                    write += pat.len();
+                    prev = true;
+                }
+                read += pat.len();
+            } else {
+                bytes[write] = bytes[read];
+                write += 1;
+                read += 1;
+                prev = false;
+            }
+        }
+        // only whole chars were removed, so this is still valid UTF-8
+        unsafe { self.set_len(write) }
+    }
+
+    /// remove the chars for which `pred` returns true and yield them.
+    ///
+    /// the chars are removed lazily, as the iterator advances. The remaining chars are
+    /// kept when the iterator is dropped, also if `pred` panics.
+    pub fn extract_if<F: FnMut(char) -> bool>(&mut self, pred: F) -> ExtractIf<'_, F> {
+        let len = self.len();
+        let inline = self.inline_bytes().copied();
+        unsafe { self.set_len(0) };
+        ExtractIf {
+            string: self,
+            inline,
+            read: 0,
+            len,
+            pred,
+        }
+    }
+
+    /// keep only the bytes in `range`, moving them to the front without reallocating.
+    ///
+    /// panics if the range is out of bounds or does not lie on char boundaries.
+    pub fn keep<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = bounds(range, self.len());
+        assert!(self.is_char_boundary(start) && self.is_char_boundary(end), "range is not on a char boundary");
+        if start != 0 {
+            self.bytes.as_mut_slice().copy_within(start .. end, 0);
+        }
+        unsafe { self.set_len(end - start) }
+    }
+
+    /// append a copy of the bytes in `range` to the end of the string.
+    ///
+    /// panics if the range is out of bounds or does not lie on char boundaries.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R) {
+        let len = self.len();
+        let (start, end) = bounds(range, len);
+        assert!(self.is_char_boundary(start) && self.is_char_boundary(end), "range is not on a char boundary");
+        let count = end - start;
+
+        // may move the buffer, so only take the pointer afterwards
+        if len + count > self.capacity() {
+            self.reserve(count);
+        }
+        unsafe {
+            let ptr = self.bytes.as_mut_ptr();
+            ptr::copy_nonoverlapping(ptr.add(start), ptr.add(len), count);
+            self.set_len(len + count);
+        }
+    }
+
+    /// split into pieces of at most `max_bytes` bytes that end on char boundaries.
+    ///
+    /// a chunk is shorter than `max_bytes` if the next char would not fit.
+    /// A char that is longer than `max_bytes` on its own is yielded as a single oversized chunk,
+    /// so no content is lost and the iterator always makes progress.
+    pub fn char_boundary_chunks(&self, max_bytes: usize) -> impl Iterator<Item=&str> + '_ {
+        let mut rest = self.as_str();
+        core::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let mut end = max_bytes.min(rest.len());
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            if end == 0 {
+                end = rest.chars().next().map_or(0, char::len_utf8);
+            }
+            let (chunk, tail) = rest.split_at(end);
+            rest = tail;
+            Some(chunk)
+        })
+    }
+
+    /// the lines of the string as owned strings, see `str::lines`.
+    ///
+    /// short lines are stored inline.
+    #[inline]
+    pub fn split_lines(&self) -> impl Iterator<Item=IString> + '_ {
+        self.as_str().lines().map(IString::from)
+    }
+
+    /// the substrings separated by `sep` as owned strings, see `str::split`.
+    ///
+    /// short substrings are stored inline.
+    #[inline]
+    pub fn split_on(&self, sep: char) -> impl Iterator<Item=IString> + '_ {
+        self.as_str().split(sep).map(IString::from)
+    }
+
+    /// the non-overlapping matches of `pat` as owned strings, see `str::matches`.
+    ///
+    /// short matches are stored inline.
+    #[inline]
+    pub fn matches_owned<'a>(&'a self, pat: &'a str) -> impl Iterator<Item=IString> + 'a {
+        self.as_str().matches(pat).map(IString::from)
+    }
+
+    /// create a new string by repeating `self` `n` times.
+    ///
+    /// panics if the resulting length overflows.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> IString {
+        let len = self.len().checked_mul(n).expect("IString::repeat: capacity overflow");
+        let mut s = IString::with_capacity(len);
+        for _ in 0 .. n {
+            s.push_str(self.as_str());
+        }
+        s
+    }
+
+    /// replace all matches of `from` with `to`, like `str::replace`.
+    #[inline]
+    #[must_use]
+    pub fn replace(&self, from: &str, to: &str) -> IString {
+        self.replacen(from, to, usize::MAX)
+    }
+
+    /// replace the first `count` matches of `from` with `to`, like `str::replacen`.
+    #[must_use]
+    pub fn replacen(&self, from: &str, to: &str, count: usize) -> IString {
+        let mut result = IString::with_capacity(self.len());
+        let mut last_end = 0;
+        for (start, part) in self.as_str().match_indices(from).take(count) {
+            result.push_str(&self[last_end .. start]);
+            result.push_str(to);
+            last_end = start + part.len();
+        }
+        result.push_str(&self[last_end ..]);
+        result
+    }
+
+    /// decode UTF-16 encoded `v` into a new string.
+    ///
+    /// returns an error if `v` contains unpaired surrogates.
+    pub fn from_utf16(v: &[u16]) -> Result<IString, FromUtf16Error> {
+        let mut s = IString::with_capacity(v.len());
+        for c in char::decode_utf16(v.iter().cloned()) {
+            match c {
+                Ok(c) => s.push(c),
+                Err(_) => return Err(FromUtf16Error(()))
+            }
+        }
+        Ok(s)
+    }
+
+    /// decode UTF-16 encoded `v` into a new string, replacing unpaired surrogates with U+FFFD.
+    pub fn from_utf16_lossy(v: &[u16]) -> IString {
+        let mut s = IString::with_capacity(v.len());
+        for c in char::decode_utf16(v.iter().cloned()) {
+            s.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        s
+    }
+
+    /// build a string from unicode code points, replacing invalid ones with U+FFFD.
+    ///
+    /// surrogates and values above `char::MAX` are invalid.
+    pub fn from_code_points_lossy(points: &[u32]) -> IString {
+        let mut s = IString::with_capacity(points.len());
+        for &p in points {
+            s.push(char::from_u32(p).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        s
+    }
+
+    /// convert `bytes` to a string, replacing invalid UTF-8 sequences with U+FFFD.
+    pub fn from_utf8_lossy(bytes: &[u8]) -> IString {
+        if let Ok(s) = str::from_utf8(bytes) {
+            return IString::from(s);
+        }
+        let mut s = IString::with_capacity(bytes.len());
+        for chunk in bytes.utf8_chunks() {
+            s.push_str(chunk.valid());
+            if !chunk.invalid().is_empty() {
+                s.push(char::REPLACEMENT_CHARACTER);
+            }
+        }
+        s
+    }
+
+    /// consume the string and leak its contents, returning a `&'static mut str`.
+    ///
+    /// inline strings are moved to the heap first.
+    /// The memory is intentionally never freed.
+    pub fn leak(mut self) -> &'static mut str {
+        let len = self.len();
+        self.move_to_heap(len);
+        let heap = self.bytes.to_heap();
+        unsafe {
+            str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(heap.ptr, heap.len))
+        }
+    }
+
+    /// move the content out as a `String`, leaving `self` empty and inline.
+    ///
+    /// a heap allocation is handed over, inline content is copied into a new one.
+    #[inline]
+    pub fn take_string(&mut self) -> String {
+        mem::take(self).into()
+    }
+
+    /// convert into the underlying bytes, reusing the allocation.
+    #[inline]
+    pub fn into_ibytes(self) -> IBytes {
+        self.bytes
+    }
+
     pub fn from_utf8(bytes: IBytes) -> Result<IString, FromUtf8Error<IBytes>> {
         match str::from_utf8(bytes.as_slice()) {
             Ok(_) => Ok(IString { bytes }),
@@ -102,12 +819,32 @@ impl IString {
         }
     }
 }
+impl Clone for IString {
+    #[inline]
+    fn clone(&self) -> IString {
+        IString {
+            bytes: self.bytes.clone()
+        }
+    }
+    /// reuses the heap allocation of `self` if it is large enough.
+    #[inline]
+    fn clone_from(&mut self, source: &IString) {
+        self.bytes.clone_from(&source.bytes);
+    }
+}
+/// see `IString::into_ibytes`.
+impl convert::From<IString> for IBytes {
+    #[inline]
+    fn from(s: IString) -> IBytes {
+        s.bytes
+    }
+}
 impl<'a> convert::From<&'a str> for IString {
     #[inline]
     fn from(s: &'a str) -> IString {
-        let mut istring = IString::with_capacity(s.len());
-        istring.push_str(s);
-        istring
+        IString {
+            bytes: IBytes::from(s.as_bytes())
+        }
     }
 }
 impl convert::From<String> for IString {
@@ -147,10 +884,16 @@ impl fmt::Write for IString {
 impl Extend<char> for IString {
     #[inline]
     fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
-        let iterator = iter.into_iter();
+        let mut iterator = iter.into_iter();
         let (lower_bound, _) = iterator.size_hint();
         self.reserve(lower_bound);
-        for ch in iterator {
+        while let Some(ch) = iterator.next() {
+            // the lower bound assumes one byte per char, make room for the rest
+            // of the iterator when it is exceeded, instead of growing one char at a time
+            let ch_len = ch.len_utf8();
+            if self.len() + ch_len > self.capacity() {
+                self.reserve(ch_len + iterator.size_hint().0);
+            }
             self.push(ch)
         }
     }
@@ -200,6 +943,21 @@ impl<'a> AddAssign<&'a str> for IString {
         self.push_str(other);
     }
 }
+impl<'a> Add<Cow<'a, str>> for IString {
+    type Output = IString;
+
+    #[inline(always)]
+    fn add(mut self, other: Cow<'a, str>) -> IString {
+        self.push_str(&other);
+        self
+    }
+}
+impl<'a> AddAssign<Cow<'a, str>> for IString {
+    #[inline]
+    fn add_assign(&mut self, other: Cow<'a, str>) {
+        self.push_str(&other);
+    }
+}
 
 impl FromIterator<char> for IString {
     fn from_iter<T>(iter: T) -> Self where T: IntoIterator<Item=char> {
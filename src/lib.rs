@@ -37,6 +37,14 @@ pub mod istring;
 pub mod small;
 pub mod ibytes;
 pub mod tiny;
+pub mod array;
+pub mod hashed;
+#[cfg(target_has_atomic="ptr")]
+pub mod shared;
+pub mod traits;
+
+#[cfg(feature="proptest")]
+pub mod proptest;
 
 #[cfg(feature="serialize")]
 use core::marker::PhantomData;
@@ -45,6 +53,66 @@ pub use crate::istring::IString;
 pub use crate::ibytes::IBytes;
 pub use crate::small::{SmallBytes, SmallString};
 pub use crate::tiny::{TinyBytes, TinyString};
+pub use crate::array::ArrayString;
+pub use crate::hashed::HashedIString;
+#[cfg(target_has_atomic="ptr")]
+pub use crate::shared::SharedString;
+pub use crate::traits::StrLike;
+
+/// build an inline `IString` from a string literal at compile time.
+///
+/// fails to compile if the literal does not fit inline.
+/// ```
+/// const GREETING: istring::IString = istring::istring!("hello");
+/// assert_eq!(GREETING, "hello");
+/// ```
+/// ```compile_fail
+/// let s = istring::istring!("this literal is longer than a string on 64bit");
+/// ```
+#[macro_export]
+macro_rules! istring {
+    ($s:expr) => {
+        const {
+            $crate::IString::new_inline($s).expect("string literal does not fit inline in an IString")
+        }
+    };
+}
+
+/// like `format!`, but creates an `IString`. Does not need `std`.
+///
+/// panics if a formatting trait implementation returns an error.
+/// ```
+/// let s = istring::format_istring!("{}-{}", 1, "a");
+/// assert_eq!(s, "1-a");
+/// ```
+#[macro_export]
+macro_rules! format_istring {
+    ($($arg:tt)*) => {{
+        let mut s = $crate::IString::new();
+        // panics like `format!` if a formatting trait implementation returns an error
+        s.push_fmt(::core::format_args!($($arg)*));
+        s
+    }};
+}
+
+/// build a `TinyString` from a string literal at compile time.
+///
+/// fails to compile if the literal is longer than 7 bytes.
+/// ```
+/// const ABC: istring::TinyString = istring::tiny!("abc");
+/// assert_eq!(ABC, "abc");
+/// ```
+/// ```compile_fail
+/// let s = istring::tiny!("too long");
+/// ```
+#[macro_export]
+macro_rules! tiny {
+    ($s:expr) => {
+        const {
+            $crate::TinyString::new($s).expect("string literal does not fit in a TinyString")
+        }
+    };
+}
 
 #[derive(Debug)]
 pub struct FromUtf8Error<T> {
@@ -77,6 +145,143 @@ impl<T: std::fmt::Debug> std::error::Error for FromUtf8Error<T> {
     }
 }
 
+#[derive(Debug)]
+pub struct FromUtf16Error(());
+
+#[cfg(feature="std")]
+impl std::fmt::Display for FromUtf16Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        "invalid utf-16: lone surrogate found".fmt(f)
+    }
+}
+#[cfg(feature="std")]
+impl std::error::Error for FromUtf16Error {}
+
+/// returned when appending to a fixed capacity type would not fit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CapacityError {
+    needed: usize,
+    available: usize,
+    rejected: Option<char>,
+}
+impl CapacityError {
+    /// number of bytes that should have been appended
+    #[inline]
+    pub fn needed(&self) -> usize {
+        self.needed
+    }
+    /// number of bytes that were still free
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.available
+    }
+    /// the char that did not fit, if the error comes from `try_push`.
+    ///
+    /// allows filling a string char by char and keeping the first char that overflowed.
+    #[inline]
+    pub fn rejected_char(&self) -> Option<char> {
+        self.rejected
+    }
+}
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "capacity exceeded: needed {} bytes, {} available", self.needed, self.available)
+    }
+}
+#[cfg(feature="std")]
+impl std::error::Error for CapacityError {}
+
+/// returned by `TinyString::try_from_utf8`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TryFromUtf8Error {
+    Utf8(core::str::Utf8Error),
+    Capacity(CapacityError),
+}
+impl core::fmt::Display for TryFromUtf8Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TryFromUtf8Error::Utf8(e) => e.fmt(f),
+            TryFromUtf8Error::Capacity(e) => e.fmt(f),
+        }
+    }
+}
+#[cfg(feature="std")]
+impl std::error::Error for TryFromUtf8Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TryFromUtf8Error::Utf8(e) => Some(e),
+            TryFromUtf8Error::Capacity(e) => Some(e),
+        }
+    }
+}
+
+/// conversion into an `IString`, like `ToString`.
+pub trait ToIString {
+    fn to_istring(&self) -> IString;
+}
+/// conversion into a `SmallString`, like `ToString`.
+pub trait ToSmallString {
+    fn to_smallstring(&self) -> SmallString;
+}
+/// conversion into a `TinyString`, `None` if longer than 7 bytes.
+pub trait ToTinyString {
+    fn to_tinystring(&self) -> Option<TinyString>;
+}
+macro_rules! impl_to_string {
+    ($($t:ty),*) => {
+        $(
+            impl ToIString for $t {
+                #[inline]
+                fn to_istring(&self) -> IString {
+                    IString::from(AsRef::<str>::as_ref(self))
+                }
+            }
+            impl ToSmallString for $t {
+                #[inline]
+                fn to_smallstring(&self) -> SmallString {
+                    SmallString::from(AsRef::<str>::as_ref(self))
+                }
+            }
+            impl ToTinyString for $t {
+                #[inline]
+                fn to_tinystring(&self) -> Option<TinyString> {
+                    TinyString::new(AsRef::<str>::as_ref(self))
+                }
+            }
+        )*
+    };
+}
+impl_to_string!(str, alloc::string::String, alloc::borrow::Cow<'_, str>);
+
+// compare the different string and bytes types by content
+macro_rules! impl_cross_eq {
+    ($($a:ty, $b:ty;)*) => {
+        $(
+            impl PartialEq<$b> for $a {
+                #[inline]
+                fn eq(&self, other: &$b) -> bool {
+                    **self == **other
+                }
+            }
+            impl PartialEq<$a> for $b {
+                #[inline]
+                fn eq(&self, other: &$a) -> bool {
+                    **self == **other
+                }
+            }
+        )*
+    };
+}
+impl_cross_eq!(
+    IString, SmallString;
+    IString, TinyString;
+    SmallString, TinyString;
+    IBytes, SmallBytes;
+    IBytes, TinyBytes;
+    SmallBytes, TinyBytes;
+);
+
+
 
 #[cfg(feature="serialize")]
 use serde::{Serialize, Serializer, Deserialize, Deserializer, de::Visitor};
@@ -117,6 +322,17 @@ impl<'de, T> Visitor<'de> for StringVisitor<T> where T: for<'a> From<&'a str> +
     }
 }
 
+/// the expected length when a tiny type does not fit, derived from `TinyBytes::INLINE_CAPACITY`.
+#[cfg(feature="serialize")]
+struct TinyCapacity;
+
+#[cfg(feature="serialize")]
+impl serde::de::Expected for TinyCapacity {
+    fn fmt(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+        write!(formatter, "at most {} bytes", TinyBytes::INLINE_CAPACITY)
+    }
+}
+
 #[cfg(feature="serialize")]
 struct TinyStringVisitor;
 
@@ -133,7 +349,23 @@ impl<'de> Visitor<'de> for TinyStringVisitor {
             E: serde::de::Error, {
 
         use serde::de::Error;
-        TinyString::new(v).ok_or(Error::invalid_length(v.len(), &"less than 8 bytes"))
+        TinyString::new(v).ok_or(Error::invalid_length(v.len(), &TinyCapacity))
+    }
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        self.visit_str(v)
+    }
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        use serde::de::{Error, Unexpected};
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(Error::invalid_value(Unexpected::Bytes(v), &self))
+        }
     }
 }
 
@@ -185,3 +417,248 @@ impl<'de> Deserialize<'de> for TinyString {
     }
 }
 
+#[cfg(feature="serialize")]
+use alloc::vec::Vec;
+
+#[cfg(feature="serialize")]
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+
+/// Human-readable formats get the bytes as a base64 string, binary formats get raw bytes.
+#[cfg(feature="serialize")]
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&BASE64.encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+#[cfg(feature="serialize")]
+fn decode_base64<E: serde::de::Error>(v: &str) -> Result<Vec<u8>, E> {
+    BASE64.decode(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &"a base64 string"))
+}
+
+#[cfg(feature="serialize")]
+struct ByteBufVisitor<T>(PhantomData<T>);
+
+#[cfg(feature="serialize")]
+impl<T> ByteBufVisitor<T> {
+    fn new() -> Self {
+        ByteBufVisitor(PhantomData)
+    }
+}
+
+#[cfg(feature="serialize")]
+impl<'de, T> Visitor<'de> for ByteBufVisitor<T> where T: for<'a> From<&'a [u8]> + From<Vec<u8>> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+        write!(formatter, "a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        Ok(T::from(v))
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        Ok(T::from(decode_base64(v)?))
+    }
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        Ok(T::from(v))
+    }
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>, {
+
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            v.push(b);
+        }
+        Ok(T::from(v))
+    }
+}
+
+#[cfg(feature="serialize")]
+struct TinyBytesVisitor;
+
+#[cfg(feature="serialize")]
+impl<'de> Visitor<'de> for TinyBytesVisitor {
+    type Value = TinyBytes;
+
+    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+        write!(formatter, "a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        TinyBytes::new(v).ok_or(E::invalid_length(v.len(), &TinyCapacity))
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error, {
+
+        self.visit_bytes(&decode_base64(v)?)
+    }
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>, {
+
+        use serde::de::Error;
+        let mut buf = [0; TinyBytes::INLINE_CAPACITY];
+        let mut len = 0;
+        while let Some(b) = seq.next_element()? {
+            if len == buf.len() {
+                return Err(Error::invalid_length(len + 1, &TinyCapacity));
+            }
+            buf[len] = b;
+            len += 1;
+        }
+        Ok(TinyBytes::new(&buf[.. len]).unwrap())
+    }
+}
+
+#[cfg(feature="serialize")]
+impl Serialize for IBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serialize_bytes(self.as_slice(), serializer)
+    }
+}
+
+#[cfg(feature="serialize")]
+impl<'de> Deserialize<'de> for IBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ByteBufVisitor::<IBytes>::new())
+        } else {
+            deserializer.deserialize_byte_buf(ByteBufVisitor::<IBytes>::new())
+        }
+    }
+}
+
+#[cfg(feature="serialize")]
+impl Serialize for SmallBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serialize_bytes(self.as_slice(), serializer)
+    }
+}
+
+#[cfg(feature="serialize")]
+impl<'de> Deserialize<'de> for SmallBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ByteBufVisitor::<SmallBytes>::new())
+        } else {
+            deserializer.deserialize_byte_buf(ByteBufVisitor::<SmallBytes>::new())
+        }
+    }
+}
+
+#[cfg(feature="serialize")]
+impl Serialize for TinyBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+#[cfg(feature="serialize")]
+impl<'de> Deserialize<'de> for TinyBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TinyBytesVisitor)
+        } else {
+            deserializer.deserialize_bytes(TinyBytesVisitor)
+        }
+    }
+}
+
+#[cfg(feature="arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <&str>::arbitrary(u).map(IString::from)
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
+
+#[cfg(feature="arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SmallString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <&str>::arbitrary(u).map(SmallString::from)
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
+
+#[cfg(feature="arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TinyString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // cut at the last char boundary that still fits
+        let s = <&str>::arbitrary(u)?;
+        let mut end = s.len().min(TinyString::INLINE_CAPACITY);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        Ok(TinyString::new(&s[.. end]).unwrap())
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
+
+#[cfg(feature="quickcheck")]
+impl quickcheck::Arbitrary for IString {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        IString::from(alloc::string::String::arbitrary(g))
+    }
+    /// yields shorter and shorter prefixes, always cut at a char boundary.
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item=Self>> {
+        let s = self.clone();
+        let ends = self.char_indices().rev().map(|(i, _)| i).collect::<alloc::vec::Vec<_>>();
+        alloc::boxed::Box::new(ends.into_iter().map(move |end| IString::from(&s[.. end])))
+    }
+}
+
+#[cfg(feature="borsh")]
+impl borsh::BorshSerialize for IString {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.as_str(), writer)
+    }
+}
+
+#[cfg(feature="borsh")]
+impl borsh::BorshDeserialize for IString {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        alloc::string::String::deserialize_reader(reader).map(IString::from)
+    }
+}
+
+#[cfg(feature="borsh")]
+impl borsh::BorshSerialize for IBytes {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.as_slice(), writer)
+    }
+}
+
+#[cfg(feature="borsh")]
+impl borsh::BorshDeserialize for IBytes {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        alloc::vec::Vec::<u8>::deserialize_reader(reader).map(IBytes::from)
+    }
+}
@@ -0,0 +1,47 @@
+//! proptest strategies for the string types.
+//!
+//! The length distribution is biased towards the inline capacity of each type,
+//! since that is where the representation changes.
+
+use ::proptest::prelude::*;
+use ::proptest::collection::vec;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{IString, SmallString, TinyString};
+
+fn ascii_around(cap: usize) -> impl Strategy<Value=String> {
+    vec(0x20u8 .. 0x7f, cap.saturating_sub(2) ..= cap + 2)
+        .prop_map(|v| String::from_utf8(v).unwrap())
+}
+
+fn string_around(cap: usize) -> impl Strategy<Value=String> {
+    prop_oneof![
+        ascii_around(cap),
+        vec(any::<char>(), 0 ..= cap).prop_map(|v| v.into_iter().collect()),
+        vec(any::<char>(), 0 ..= 4 * cap).prop_map(|v| v.into_iter().collect()),
+    ]
+}
+
+/// strings of any length, frequently close to `IString::INLINE_CAPACITY`.
+pub fn any_istring() -> impl Strategy<Value=IString> {
+    string_around(IString::INLINE_CAPACITY).prop_map(IString::from)
+}
+
+/// strings of any length, frequently close to `SmallString::INLINE_CAPACITY`.
+pub fn any_smallstring() -> impl Strategy<Value=SmallString> {
+    string_around(SmallString::INLINE_CAPACITY).prop_map(SmallString::from)
+}
+
+/// strings that fit into a `TinyString`, including full ones.
+pub fn any_tinystring() -> impl Strategy<Value=TinyString> {
+    vec(any::<char>(), 0 ..= TinyString::INLINE_CAPACITY).prop_map(|chars: Vec<char>| {
+        let mut s = TinyString::new("").unwrap();
+        for c in chars {
+            if s.try_push(c).is_err() {
+                break;
+            }
+        }
+        s
+    })
+}
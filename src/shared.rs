@@ -0,0 +1,227 @@
+//! a string with cheap clones, backed by an `Arc<str>` when it does not fit inline.
+
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt, str};
+use alloc::string::String;
+use alloc::sync::Arc;
+
+use crate::{IString, SmallString, TinyString};
+
+const INLINE_CAPACITY: usize = 22;
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAPACITY] },
+    Heap(Arc<str>),
+}
+
+/// an immutable string that stores up to 22 bytes inline and shares longer strings.
+///
+/// cloning never allocates: inline strings are copied, heap strings bump the reference count.
+#[derive(Clone)]
+pub struct SharedString(Repr);
+
+impl SharedString {
+    /// the maximum length of a string that is stored inline.
+    pub const INLINE_CAPACITY: usize = INLINE_CAPACITY;
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> SharedString {
+        SharedString(Repr::Inline { len: 0, buf: [0; INLINE_CAPACITY] })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self.0 {
+            Repr::Inline { len, ref buf } => unsafe {
+                str::from_utf8_unchecked(&buf[.. len as usize])
+            },
+            Repr::Heap(ref arc) => arc,
+        }
+    }
+
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline { .. })
+    }
+
+    /// a mutable view of the string, copying it first if the allocation is shared.
+    ///
+    /// inline strings and uniquely owned heap strings are not copied.
+    pub fn make_mut(&mut self) -> &mut str {
+        match self.0 {
+            Repr::Inline { len, ref mut buf } => unsafe {
+                str::from_utf8_unchecked_mut(&mut buf[.. len as usize])
+            },
+            Repr::Heap(ref mut arc) => {
+                if Arc::get_mut(arc).is_none() {
+                    *arc = Arc::from(&**arc);
+                }
+                Arc::get_mut(arc).unwrap()
+            }
+        }
+    }
+
+    /// true if both are heap strings that share the same allocation.
+    #[inline]
+    pub fn ptr_eq(&self, other: &SharedString) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Heap(a), Repr::Heap(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Default for SharedString {
+    #[inline]
+    fn default() -> SharedString {
+        SharedString::new()
+    }
+}
+impl Deref for SharedString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl AsRef<str> for SharedString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl AsRef<[u8]> for SharedString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl Borrow<str> for SharedString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// hashes exactly like the `str` it contains.
+impl Hash for SharedString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+impl PartialEq for SharedString {
+    #[inline]
+    fn eq(&self, other: &SharedString) -> bool {
+        self.ptr_eq(other) || self.as_str() == other.as_str()
+    }
+}
+impl Eq for SharedString {}
+impl PartialEq<str> for SharedString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl<'a> PartialEq<&'a str> for SharedString {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+impl PartialEq<String> for SharedString {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+impl PartialOrd for SharedString {
+    #[inline]
+    fn partial_cmp(&self, other: &SharedString) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SharedString {
+    #[inline]
+    fn cmp(&self, other: &SharedString) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl fmt::Debug for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+impl fmt::Display for SharedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// copies `s` inline, or into a new `Arc<str>` if it is too long.
+impl<'a> From<&'a str> for SharedString {
+    fn from(s: &'a str) -> SharedString {
+        if s.len() <= INLINE_CAPACITY {
+            let mut buf = [0; INLINE_CAPACITY];
+            buf[.. s.len()].copy_from_slice(s.as_bytes());
+            SharedString(Repr::Inline { len: s.len() as u8, buf })
+        } else {
+            SharedString(Repr::Heap(Arc::from(s)))
+        }
+    }
+}
+impl From<String> for SharedString {
+    #[inline]
+    fn from(s: String) -> SharedString {
+        SharedString::from(s.as_str())
+    }
+}
+impl From<IString> for SharedString {
+    #[inline]
+    fn from(s: IString) -> SharedString {
+        SharedString::from(s.as_str())
+    }
+}
+impl From<SmallString> for SharedString {
+    #[inline]
+    fn from(s: SmallString) -> SharedString {
+        SharedString::from(s.as_str())
+    }
+}
+impl From<TinyString> for SharedString {
+    #[inline]
+    fn from(s: TinyString) -> SharedString {
+        SharedString::from(s.as_str())
+    }
+}
+/// shares the allocation.
+impl From<Arc<str>> for SharedString {
+    #[inline]
+    fn from(arc: Arc<str>) -> SharedString {
+        SharedString(Repr::Heap(arc))
+    }
+}
+impl From<SharedString> for IString {
+    #[inline]
+    fn from(s: SharedString) -> IString {
+        IString::from(s.as_str())
+    }
+}
+impl From<SharedString> for String {
+    #[inline]
+    fn from(s: SharedString) -> String {
+        String::from(s.as_str())
+    }
+}
+
+#[cfg(target_pointer_width="64")]
+#[test]
+fn test_size() {
+    assert_eq!(core::mem::size_of::<SharedString>(), 24);
+}
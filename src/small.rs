@@ -4,7 +4,7 @@ use core::ops::{self, Index};
 use core::borrow::Borrow;
 use alloc::{string::String, vec::Vec};
 use alloc::boxed::Box;
-use crate::FromUtf8Error;
+use crate::{FromUtf8Error, IBytes, IString};
 
 #[cfg(feature="ts")]
 use alloc::{borrow::ToOwned, format};
@@ -65,6 +65,8 @@ union SmallBytesUnion {
 pub struct SmallBytes {
     union: SmallBytesUnion,
 }
+// SAFETY: the heap pointer is uniquely owned, like a `Box<[u8]>`,
+// and only mutated through `&mut self`. `SmallString` inherits both.
 unsafe impl Send for SmallBytes {}
 unsafe impl Sync for SmallBytes {}
 
@@ -137,15 +139,36 @@ fn test_layout() {
     assert_eq!(heap.len, MAX_CAPACITY + 1);
 }
 
+#[test]
+fn test_len_byte_is_msb() {
+    // on either endianness the inline len byte overlaps the most significant byte of heap.len
+    let s = SmallBytesUnion { inline: Inline { data: [0xff; INLINE_CAPACITY], len: IS_INLINE | 3 } };
+    let heap = unsafe { s.heap };
+    assert_eq!(heap.len.to_be_bytes()[0], IS_INLINE | 3);
+}
+
+#[cfg(feature="bytemuck")]
+unsafe impl bytemuck::Zeroable for Inline {}
+
+#[test]
+fn test_len() {
+    let data = [0xab; 100];
+    for i in 0 .. data.len() {
+        let b = SmallBytes::from(&data[.. i]);
+        assert_eq!(b.len(), i);
+        assert_eq!(b.len(), b.len_portable());
+    }
+}
+
 #[inline(always)]
 fn box_slice(s: &[u8]) -> Box<[u8]> {
     Box::from(s)
 }
 #[inline(always)]
-fn box_slice_into_raw_parts(mut s: Box<[u8]>) -> (*mut u8, usize) {
+fn box_slice_into_raw_parts(s: Box<[u8]>) -> (*mut u8, usize) {
     let len = s.len();
-    let ptr = s.as_mut_ptr();
-    mem::forget(s);
+    // Box::into_raw keeps the pointer valid, unlike as_mut_ptr + forget
+    let ptr = Box::into_raw(s) as *mut u8;
     (ptr, len)
 }
 #[inline(always)]
@@ -156,6 +179,7 @@ unsafe fn box_slice_from_raw_parts(ptr: *mut u8, len: usize) -> Box<[u8]> {
 
 impl SmallBytes {
     #[inline(always)]
+    #[must_use]
     pub fn new() -> SmallBytes {
         unsafe {
             SmallBytes::from_inline(
@@ -163,6 +187,35 @@ impl SmallBytes {
             )
         }
     }
+    /// the size of the heap allocation, or `None` if inline.
+    #[inline]
+    pub fn heap_capacity(&self) -> Option<usize> {
+        if self.is_inline() {
+            None
+        } else {
+            Some(self.len())
+        }
+    }
+    /// move the bytes inline and free the heap allocation, if they fit.
+    ///
+    /// returns whether the bytes are inline afterwards.
+    pub fn shrink_to_inline(&mut self) -> bool {
+        if self.is_inline() {
+            return true;
+        }
+        let len = self.len();
+        if len > INLINE_CAPACITY {
+            return false;
+        }
+        unsafe {
+            let heap = self.union.heap;
+            let mut data = [0; INLINE_CAPACITY];
+            data[.. len].copy_from_slice(slice::from_raw_parts(heap.ptr, len));
+            drop(box_slice_from_raw_parts(heap.ptr, len));
+            self.union.inline = Inline { data, len: len as u8 | IS_INLINE };
+        }
+        true
+    }
 }
 impl<'a> From<&'a [u8]> for SmallBytes {
     #[inline]
@@ -190,12 +243,40 @@ impl<'a> From<&'a [u8]> for SmallBytes {
 }
 
 impl SmallString {
+    /// the maximum length of a string that can be stored without a heap allocation.
+    pub const INLINE_CAPACITY: usize = SmallBytes::INLINE_CAPACITY;
+
     #[inline(always)]
+    #[must_use]
     pub fn new() -> SmallString {
         SmallString {
             bytes: SmallBytes::new()
         }
     }
+    /// create a new string by repeating `self` `n` times.
+    ///
+    /// panics if the resulting length overflows.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> SmallString {
+        let len = self.len().checked_mul(n).expect("SmallString::repeat: capacity overflow");
+        let mut s = String::with_capacity(len);
+        for _ in 0 .. n {
+            s.push_str(self.as_str());
+        }
+        s.into()
+    }
+    /// the size of the heap allocation, or `None` if inline.
+    #[inline(always)]
+    pub fn heap_capacity(&self) -> Option<usize> {
+        self.bytes.heap_capacity()
+    }
+    /// move the string inline and free the heap allocation, if it fits.
+    ///
+    /// returns whether the string is inline afterwards.
+    #[inline]
+    pub fn shrink_to_inline(&mut self) -> bool {
+        self.bytes.shrink_to_inline()
+    }
     pub fn from_utf8(bytes: SmallBytes) -> Result<SmallString, FromUtf8Error<SmallBytes>> {
         match str::from_utf8(bytes.as_slice()) {
             Ok(_) => Ok(SmallString { bytes }),
@@ -254,6 +335,46 @@ impl convert::From<String> for SmallString {
         }
     }
 }
+/// reuses the allocation if it is heap-backed, longer than the inline capacity and `cap == len`.
+impl From<IBytes> for SmallBytes {
+    #[inline]
+    fn from(b: IBytes) -> SmallBytes {
+        if b.len() <= INLINE_CAPACITY {
+            return SmallBytes::from(b.as_slice());
+        }
+        // `Vec::into_boxed_slice` only reallocates if there is spare capacity
+        SmallBytes::from(Vec::from(b))
+    }
+}
+/// reuses the allocation if it is heap-backed.
+impl From<SmallBytes> for IBytes {
+    #[inline]
+    fn from(b: SmallBytes) -> IBytes {
+        if b.is_inline() {
+            return IBytes::from(b.as_slice());
+        }
+        let v: Vec<u8> = b.into();
+        IBytes::from(v)
+    }
+}
+/// see `From<IBytes> for SmallBytes`.
+impl From<IString> for SmallString {
+    #[inline]
+    fn from(s: IString) -> SmallString {
+        SmallString {
+            bytes: SmallBytes::from(s.bytes)
+        }
+    }
+}
+/// see `From<SmallBytes> for IBytes`.
+impl From<SmallString> for IString {
+    #[inline]
+    fn from(s: SmallString) -> IString {
+        IString {
+            bytes: IBytes::from(s.bytes)
+        }
+    }
+}
 impl Into<Vec<u8>> for SmallBytes {
     #[inline]
     fn into(self) -> Vec<u8> {
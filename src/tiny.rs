@@ -1,4 +1,5 @@
 use core::{borrow::Borrow, fmt::Debug, hash::Hash, ops::Deref};
+use crate::{IBytes, IString, SmallBytes, SmallString, CapacityError, TryFromUtf8Error};
 
 #[cfg(feature="ts")]
 use alloc::{borrow::ToOwned, string::String, format};
@@ -14,14 +15,23 @@ pub struct TinyBytes {
 #[cfg_attr(feature="ts", derive(ts_rs::TS), ts(type="String"))]
 pub struct TinyString(TinyBytes);
 
+// all-zero is the empty string
+#[cfg(feature="bytemuck")]
+unsafe impl bytemuck::Zeroable for TinyBytes {}
+#[cfg(feature="bytemuck")]
+unsafe impl bytemuck::Zeroable for TinyString {}
+
 impl TinyBytes {
+    /// the maximum number of bytes that can be stored.
+    pub const INLINE_CAPACITY: usize = 7;
+
     #[inline]
     pub const fn new(s: &[u8]) -> Option<Self> {
         let len = s.len();
-        if len >= 8 {
+        if len > TinyBytes::INLINE_CAPACITY {
             return None;
         }
-        let mut buf = [0; 7];
+        let mut buf = [0; TinyBytes::INLINE_CAPACITY];
         let mut i = 0;
         while i < len {
             buf[i] = s[i];
@@ -38,6 +48,39 @@ impl TinyBytes {
     pub fn as_bytes(&self) -> &[u8] {
         &**self
     }
+
+    /// copy the bytes into an array, if the length is exactly `N`.
+    #[inline]
+    pub fn to_array<const N: usize>(&self) -> Option<[u8; N]> {
+        self.as_bytes().try_into().ok()
+    }
+
+    /// always `0`, the bytes are never on the heap.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        0
+    }
+
+    /// the total memory used, always `size_of::<Self>()`.
+    #[inline(always)]
+    pub fn size_of_stored(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// view as `&str` if the bytes are valid UTF-8.
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.as_bytes())
+    }
+
+    /// view as `&str` without checking.
+    ///
+    /// # Safety
+    /// the bytes must be valid UTF-8.
+    #[inline]
+    pub unsafe fn as_str_unchecked(&self) -> &str {
+        core::str::from_utf8_unchecked(self.as_bytes())
+    }
 }
 impl Deref for TinyBytes {
     type Target = [u8];
@@ -77,6 +120,9 @@ impl AsRef<str> for TinyString {
 }
 
 impl TinyString {
+    /// the maximum length of a string that can be stored.
+    pub const INLINE_CAPACITY: usize = TinyBytes::INLINE_CAPACITY;
+
     pub const fn new(s: &str) -> Option<Self> {
         match TinyBytes::new(s.as_bytes()) {
             Some(b) => Some(TinyString(b)),
@@ -87,6 +133,136 @@ impl TinyString {
     pub fn as_str(&self) -> &str {
         &**self
     }
+
+    /// always `0`, the string is never on the heap.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        0
+    }
+
+    /// the total memory used, always `size_of::<Self>()`.
+    #[inline(always)]
+    pub fn size_of_stored(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// validate and copy borrowed bytes.
+    ///
+    /// fails if `bytes` is not valid UTF-8 or longer than 7 bytes.
+    pub fn try_from_utf8(bytes: &[u8]) -> Result<TinyString, TryFromUtf8Error> {
+        let s = core::str::from_utf8(bytes).map_err(TryFromUtf8Error::Utf8)?;
+        TinyString::new(s).ok_or(TryFromUtf8Error::Capacity(CapacityError {
+            needed: bytes.len(),
+            available: TinyString::INLINE_CAPACITY,
+            rejected: None,
+        }))
+    }
+
+    /// append `s` if it fits, otherwhise leave `self` unchanged and return an error.
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let len = self.0.len as usize;
+        let new_len = len + s.len();
+        if new_len > TinyString::INLINE_CAPACITY {
+            return Err(CapacityError { needed: s.len(), available: TinyString::INLINE_CAPACITY - len, rejected: None });
+        }
+        self.0.buf[len .. new_len].copy_from_slice(s.as_bytes());
+        self.0.len = new_len as u8;
+        Ok(())
+    }
+
+    /// append `ch` if it fits, otherwhise leave `self` unchanged and return an error.
+    ///
+    /// the error gives `ch` back through `CapacityError::rejected_char`.
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError> {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf))
+            .map_err(|e| CapacityError { rejected: Some(ch), ..e })
+    }
+
+    /// create a new string by repeating `self` `n` times if it fits, otherwise return an error.
+    pub fn repeat(&self, n: usize) -> Result<TinyString, CapacityError> {
+        let needed = self.len().saturating_mul(n);
+        if needed > TinyString::INLINE_CAPACITY {
+            return Err(CapacityError { needed, available: TinyString::INLINE_CAPACITY, rejected: None });
+        }
+        let mut s = TinyString(TinyBytes { buf: [0; TinyBytes::INLINE_CAPACITY], len: needed as u8 });
+        if needed != 0 {
+            for chunk in s.0.buf[.. needed].chunks_exact_mut(self.len()) {
+                chunk.copy_from_slice(self.as_bytes());
+            }
+        }
+        Ok(s)
+    }
+
+    /// split into two string slices at byte position `mid`.
+    ///
+    /// panics if `mid` is not on a char boundary or past the end.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (&str, &str) {
+        self.as_str().split_at(mid)
+    }
+
+    #[inline]
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        self.as_str().chars()
+    }
+
+    #[inline]
+    pub fn char_indices(&self) -> core::str::CharIndices<'_> {
+        self.as_str().char_indices()
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> core::str::Bytes<'_> {
+        self.as_str().bytes()
+    }
+
+    /// the chars in reverse order.
+    #[inline]
+    pub fn chars_rev(&self) -> core::iter::Rev<core::str::Chars<'_>> {
+        self.as_str().chars().rev()
+    }
+
+    #[inline]
+    pub fn first_char(&self) -> Option<char> {
+        self.as_str().chars().next()
+    }
+
+    #[inline]
+    pub fn last_char(&self) -> Option<char> {
+        self.as_str().chars().next_back()
+    }
+
+    /// convert all ASCII characters to upper case, in place.
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        self.0.buf[.. self.0.len as usize].make_ascii_uppercase();
+    }
+
+    /// convert all ASCII characters to lower case, in place.
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        self.0.buf[.. self.0.len as usize].make_ascii_lowercase();
+    }
+
+    /// returns a copy with all ASCII characters converted to upper case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> TinyString {
+        let mut s = *self;
+        s.make_ascii_uppercase();
+        s
+    }
+
+    /// returns a copy with all ASCII characters converted to lower case.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> TinyString {
+        let mut s = *self;
+        s.make_ascii_lowercase();
+        s
+    }
 }
 
 impl Debug for TinyBytes {
@@ -106,8 +282,33 @@ impl<T: AsRef<[u8]>> PartialEq<T> for TinyBytes {
         self.as_bytes().eq(other.as_ref())
     }
 }
+impl PartialEq<[u8]> for TinyBytes {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+impl PartialEq<TinyBytes> for [u8] {
+    #[inline]
+    fn eq(&self, other: &TinyBytes) -> bool {
+        self == other.as_bytes()
+    }
+}
+impl PartialEq<TinyBytes> for &[u8] {
+    #[inline]
+    fn eq(&self, other: &TinyBytes) -> bool {
+        *self == other.as_bytes()
+    }
+}
+impl PartialEq<TinyBytes> for alloc::vec::Vec<u8> {
+    #[inline]
+    fn eq(&self, other: &TinyBytes) -> bool {
+        self.as_slice() == other.as_bytes()
+    }
+}
 impl Eq for TinyBytes {}
 
+/// orders lexicographically by bytes, like `[u8]`.
 impl<T: AsRef<[u8]>> PartialOrd<T> for TinyBytes {
     #[inline]
     fn partial_cmp(&self, other: &T) -> Option<core::cmp::Ordering> {
@@ -141,12 +342,14 @@ impl Ord for TinyString {
         self.as_bytes().cmp(other.as_bytes())
     }
 }
+/// hashes exactly like `[u8]`, see `IBytes`.
 impl Hash for TinyBytes {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_bytes().hash(state)
     }
 }
+/// hashes exactly like `str`, see `IString`.
 impl Hash for TinyString {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -165,10 +368,90 @@ impl Borrow<str> for TinyString {
         self.as_str()
     }
 }
+#[cfg(feature="std")]
+impl AsRef<std::ffi::OsStr> for TinyString {
+    #[inline]
+    fn as_ref(&self) -> &std::ffi::OsStr {
+        self.as_str().as_ref()
+    }
+}
+/// always stays inline.
+impl From<TinyBytes> for IBytes {
+    #[inline]
+    fn from(b: TinyBytes) -> IBytes {
+        IBytes::inline_from_slice(b.as_bytes())
+    }
+}
+/// always stays inline.
+impl From<TinyBytes> for SmallBytes {
+    #[inline]
+    fn from(b: TinyBytes) -> SmallBytes {
+        SmallBytes::from(b.as_bytes())
+    }
+}
+/// always stays inline.
+impl From<TinyString> for IString {
+    #[inline]
+    fn from(s: TinyString) -> IString {
+        IString { bytes: s.0.into() }
+    }
+}
+/// always stays inline.
+impl From<TinyString> for SmallString {
+    #[inline]
+    fn from(s: TinyString) -> SmallString {
+        SmallString::from(s.as_str())
+    }
+}
+/// returns the original string if it is longer than 7 bytes.
+impl TryFrom<IString> for TinyString {
+    type Error = IString;
+
+    #[inline]
+    fn try_from(s: IString) -> Result<TinyString, IString> {
+        TinyString::new(s.as_str()).ok_or(s)
+    }
+}
+/// returns the original string if it is longer than 7 bytes.
+impl TryFrom<SmallString> for TinyString {
+    type Error = SmallString;
+
+    #[inline]
+    fn try_from(s: SmallString) -> Result<TinyString, SmallString> {
+        TinyString::new(s.as_str()).ok_or(s)
+    }
+}
+/// fails if `bytes` is longer than 7 bytes.
+impl TryFrom<&[u8]> for TinyBytes {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<TinyBytes, CapacityError> {
+        TinyBytes::new(bytes).ok_or(CapacityError {
+            needed: bytes.len(),
+            available: TinyBytes::INLINE_CAPACITY,
+            rejected: None,
+        })
+    }
+}
+/// arrays longer than 7 bytes fail to compile.
+/// ```compile_fail
+/// # use istring::TinyBytes;
+/// let _ = TinyBytes::from([0u8; 8]);
+/// ```
+impl<const N: usize> From<[u8; N]> for TinyBytes {
+    #[inline]
+    fn from(bytes: [u8; N]) -> TinyBytes {
+        const { assert!(N <= TinyBytes::INLINE_CAPACITY, "TinyBytes holds at most 7 bytes") };
+        let mut buf = [0; TinyBytes::INLINE_CAPACITY];
+        buf[.. N].copy_from_slice(&bytes);
+        TinyBytes { len: N as u8, buf }
+    }
+}
 impl From<char> for TinyString {
     #[inline]
     fn from(value: char) -> Self {
-        let mut buf = [0; 7];
+        let mut buf = [0; TinyString::INLINE_CAPACITY];
         let len = value.encode_utf8(&mut buf).len() as u8;
         TinyString(TinyBytes { len, buf })
     }
@@ -0,0 +1,52 @@
+//! traits shared by the string types of this crate.
+
+use alloc::string::String;
+
+use crate::{IString, SmallString, TinyString};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// uniform access to the string types of this crate, `String` and `str`.
+///
+/// this trait is sealed and can not be implemented outside of this crate.
+pub trait StrLike: private::Sealed {
+    /// the contents as a `&str`.
+    fn as_str(&self) -> &str;
+
+    /// the length in bytes.
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// true if the length is zero.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_str_like {
+    ($($t:ty),*) => { $(
+        impl private::Sealed for $t {}
+        impl StrLike for $t {
+            #[inline]
+            fn as_str(&self) -> &str {
+                self
+            }
+        }
+    )* }
+}
+impl_str_like!(IString, SmallString, TinyString, String, str);
+#[cfg(target_has_atomic="ptr")]
+impl_str_like!(crate::SharedString);
+
+impl<const N: usize> private::Sealed for crate::ArrayString<N> {}
+impl<const N: usize> StrLike for crate::ArrayString<N> {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
@@ -0,0 +1,98 @@
+// counts the allocations made by the current thread
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use istring::{IString, SharedString};
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn count<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(|n| n.get());
+    let t = f();
+    (t, ALLOCATIONS.with(|n| n.get()) - before)
+}
+
+#[test]
+fn test_constructor_allocations() {
+    let cap = IString::INLINE_CAPACITY;
+    let inline = "x".repeat(cap);
+    let heap = "x".repeat(cap + 1);
+
+    let (s, n) = count(|| IString::from(inline.as_str()));
+    assert_eq!(n, 0);
+    assert!(s.is_inline());
+
+    let (s, n) = count(|| IString::from(heap.as_str()));
+    assert_eq!(n, 1);
+    assert!(!s.is_inline());
+
+    let (s, n) = count(|| IString::with_capacity(cap));
+    assert_eq!(n, 0);
+    assert!(s.is_inline());
+
+    let (s, n) = count(|| IString::with_capacity(cap + 1));
+    assert_eq!(n, 1);
+    assert!(!s.is_inline());
+
+    let (s, n) = count(|| IString::new_inline(&inline).unwrap());
+    assert_eq!(n, 0);
+    assert!(s.is_inline());
+
+    let (_, n) = count(|| {
+        let mut s = IString::new();
+        s.push_str(&heap);
+        s
+    });
+    assert_eq!(n, 1);
+}
+
+#[test]
+fn test_collect_chars_allocations() {
+    let ascii: Vec<char> = (0 .. 100).map(|i| (b'a' + i % 26) as char).collect();
+    let (s, n) = count(|| ascii.iter().copied().collect::<IString>());
+    assert!(n <= 1, "{} allocations", n);
+    assert_eq!(s.len(), 100);
+
+    let (s, n) = count(|| ascii[.. 10].iter().copied().collect::<IString>());
+    assert_eq!(n, 0);
+    assert!(s.is_inline());
+
+    // non-ASCII chars exceed the lower bound, which is one byte per char
+    let wide = vec!['ä'; 100];
+    let (s, n) = count(|| wide.into_iter().collect::<IString>());
+    assert!(n <= 2, "{} allocations", n);
+    assert_eq!(s.len(), 200);
+}
+
+#[test]
+fn test_shared_clone_allocations() {
+    let long = SharedString::from("a string that is too long to be stored inline");
+    let (clone, n) = count(|| long.clone());
+    assert_eq!(n, 0);
+    assert!(clone.ptr_eq(&long));
+
+    let short = SharedString::from("short");
+    let (_, n) = count(|| short.clone());
+    assert_eq!(n, 0);
+}
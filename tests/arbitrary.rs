@@ -0,0 +1,32 @@
+#![cfg(feature="arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use istring::{IString, SmallString, TinyString};
+
+#[test]
+fn test_arbitrary() {
+    let data = "Hello Wörld! this is long enough for the heap".as_bytes();
+
+    let s = IString::arbitrary(&mut Unstructured::new(data)).unwrap();
+    let expected = <&str>::arbitrary(&mut Unstructured::new(data)).unwrap();
+    assert_eq!(s, expected);
+
+    let s = SmallString::arbitrary(&mut Unstructured::new(data)).unwrap();
+    assert_eq!(s, expected);
+
+    let s = TinyString::arbitrary(&mut Unstructured::new(data)).unwrap();
+    assert!(expected.starts_with(s.as_str()));
+    assert!(s.len() <= TinyString::INLINE_CAPACITY);
+}
+
+#[test]
+fn test_arbitrary_tiny_char_boundary() {
+    // every possible cut must stay within the capacity and on a char boundary
+    let text = "aé€𝄞aé€𝄞";
+    for start in 0 .. text.len() {
+        let data = &text.as_bytes()[start ..];
+        if let Ok(s) = TinyString::arbitrary(&mut Unstructured::new(data)) {
+            assert!(s.len() <= TinyString::INLINE_CAPACITY);
+        }
+    }
+}
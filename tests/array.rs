@@ -0,0 +1,67 @@
+use std::fmt::Write;
+use istring::{ArrayString, IString};
+
+#[test]
+fn test_array_string_new() {
+    let s = ArrayString::<4>::new("abcd").unwrap();
+    assert_eq!(s, "abcd");
+    assert_eq!(s.remaining_capacity(), 0);
+    assert!(ArrayString::<4>::new("abcde").is_none());
+
+    let empty = ArrayString::<0>::new("").unwrap();
+    assert_eq!(empty, "");
+    assert!(ArrayString::<0>::new("a").is_none());
+
+    let full = "x".repeat(255);
+    let s = ArrayString::<255>::new(&full).unwrap();
+    assert_eq!(s.len(), 255);
+    assert!(ArrayString::<255>::new(&(full + "x")).is_none());
+
+    const CONST: ArrayString<16> = ArrayString::new("const").unwrap();
+    assert_eq!(CONST, "const");
+    assert_eq!(ArrayString::<8>::default(), "");
+}
+
+#[test]
+fn test_array_string_push() {
+    let mut s = ArrayString::<5>::new("ab").unwrap();
+    s.push_str("cd").unwrap();
+    assert_eq!(s, "abcd");
+
+    let err = s.push_str("ef").unwrap_err();
+    assert_eq!((err.needed(), err.available()), (2, 1));
+    assert_eq!(s, "abcd");
+
+    assert!(s.try_push('ä').is_err());
+    s.try_push('e').unwrap();
+    assert_eq!(s, "abcde");
+    assert_eq!(s.try_push('f').unwrap_err().rejected_char(), Some('f'));
+
+    s.clear();
+    s.try_push('ä').unwrap();
+    s.try_push('ö').unwrap();
+    assert_eq!(s, "äö");
+    assert_eq!(s.remaining_capacity(), 1);
+
+    let mut s = ArrayString::<32>::default();
+    write!(s, "{}-{}", 1, 2).unwrap();
+    assert_eq!(s, "1-2");
+    assert!(write!(s, "{:40}", "").is_err());
+}
+
+#[test]
+fn test_array_string_traits() {
+    use std::collections::HashSet;
+
+    let a = ArrayString::<8>::try_from("key").unwrap();
+    assert_eq!(ArrayString::<2>::try_from("key"), Err("key"));
+    assert!(a < ArrayString::<8>::new("kez").unwrap());
+    assert_eq!(format!("{:?}|{:>5}", a, a), "\"key\"|  key");
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(set.contains("key"));
+
+    assert_eq!(IString::from(a), "key");
+    assert_eq!(std::mem::size_of::<ArrayString<15>>(), 16);
+}
@@ -0,0 +1,42 @@
+#![cfg(target_endian="big")]
+// run with `cargo +nightly miri test --target s390x-unknown-linux-gnu`
+
+use istring::{IString, SmallString};
+use istring::ibytes::InlineOrHeap;
+
+#[test]
+fn test_istring_big_endian() {
+    let mut s = IString::new();
+    let mut reference = String::new();
+    for i in 0 .. 40 {
+        assert_eq!(s.is_inline(), i <= IString::INLINE_CAPACITY);
+        assert_eq!(s.len(), i);
+        s.push('a');
+        reference.push('a');
+    }
+    assert_eq!(s, reference);
+    s.truncate(3);
+    s.shrink();
+    assert!(s.is_inline());
+    assert_eq!(s, "aaa");
+
+    match IString::from("abc").into_inline_or_heap() {
+        InlineOrHeap::Inline(inline) => assert_eq!(inline.len, 3),
+        InlineOrHeap::Heap(_) => panic!("expected inline"),
+    }
+    let s: String = IString::from("a string that is long enough for the heap").into();
+    assert_eq!(s, "a string that is long enough for the heap");
+}
+
+#[test]
+fn test_smallstring_big_endian() {
+    let mut reference = String::new();
+    for i in 0 .. 40 {
+        let s = SmallString::from(reference.as_str());
+        assert_eq!(s.len(), i);
+        assert_eq!(s.heap_capacity().is_none(), i <= SmallString::INLINE_CAPACITY);
+        let back: String = s.into();
+        assert_eq!(back, reference);
+        reference.push('a');
+    }
+}
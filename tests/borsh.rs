@@ -0,0 +1,25 @@
+#![cfg(feature="borsh")]
+
+use istring::{IBytes, IString};
+
+#[test]
+fn test_borsh_istring() {
+    for s in ["", "short", "Hello World! this one is stored on the heap"] {
+        let encoded = borsh::to_vec(&IString::from(s)).unwrap();
+        assert_eq!(encoded, borsh::to_vec(&String::from(s)).unwrap());
+        let decoded: IString = borsh::from_slice(&encoded).unwrap();
+        assert_eq!(decoded, s);
+    }
+    let invalid = borsh::to_vec(&vec![0xffu8, 0xfe]).unwrap();
+    assert!(borsh::from_slice::<IString>(&invalid).is_err());
+}
+
+#[test]
+fn test_borsh_ibytes() {
+    for b in [&b""[..], &b"\x00\xff"[..], &[0xab; 100][..]] {
+        let encoded = borsh::to_vec(&IBytes::from(b)).unwrap();
+        assert_eq!(encoded, borsh::to_vec(&b.to_vec()).unwrap());
+        let decoded: IBytes = borsh::from_slice(&encoded).unwrap();
+        assert_eq!(decoded, *b);
+    }
+}
@@ -0,0 +1,21 @@
+#![cfg(feature="bytemuck")]
+
+use bytemuck::Zeroable;
+use istring::{ibytes, small, IBytes, SmallBytes, TinyBytes, TinyString};
+
+#[test]
+fn test_zeroed_inline() {
+    let b = unsafe { IBytes::from_inline(ibytes::Inline::zeroed()) };
+    assert!(b.is_inline());
+    assert_eq!(b, IBytes::new());
+
+    let b = unsafe { SmallBytes::from_inline(small::Inline::zeroed()) };
+    assert!(b.is_inline());
+    assert_eq!(b, SmallBytes::new());
+}
+
+#[test]
+fn test_zeroed_tiny() {
+    assert_eq!(TinyBytes::zeroed(), TinyBytes::new(b"").unwrap());
+    assert_eq!(TinyString::zeroed(), "");
+}
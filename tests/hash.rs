@@ -0,0 +1,86 @@
+use istring::{IBytes, IString, SmallBytes, SmallString, TinyBytes, TinyString};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash + ?Sized>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_hash_string_like_str() {
+    for s in ["", "x", "Hello World! .........xyz"] {
+        assert_eq!(hash(&IString::from(s)), hash(s));
+        assert_eq!(hash(&SmallString::from(s)), hash(s));
+    }
+    assert_eq!(hash(&TinyString::new("x").unwrap()), hash("x"));
+}
+
+#[test]
+fn test_hash_bytes_like_slice() {
+    for s in [&b""[..], b"x", b"Hello World! .........xyz"] {
+        assert_eq!(hash(&IBytes::from(s)), hash(s));
+        assert_eq!(hash(&SmallBytes::from(s)), hash(s));
+    }
+    assert_eq!(hash(&TinyBytes::new(b"x").unwrap()), hash(&b"x"[..]));
+}
+
+#[test]
+fn test_borrow_bytes_lookup() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    map.insert(IBytes::from(&b"key"[..]), 1);
+    map.insert(IBytes::from(&b"a much longer key that lives on the heap"[..]), 2);
+    assert_eq!(map.get(&b"key"[..]), Some(&1));
+    assert_eq!(map.get(&b"a much longer key that lives on the heap"[..]), Some(&2));
+    assert_eq!(map.get(&b"missing"[..]), None);
+
+    let mut map = HashMap::new();
+    map.insert(SmallBytes::from(&b"key"[..]), 1);
+    assert_eq!(map.get(&b"key"[..]), Some(&1));
+
+    let mut map = HashMap::new();
+    map.insert(TinyBytes::new(b"key").unwrap(), 1);
+    assert_eq!(map.get(&b"key"[..]), Some(&1));
+}
+
+#[test]
+fn test_string_map_lookup() {
+    use std::collections::HashMap;
+
+    let keys = ["", "key", "a much longer key that lives on the heap"];
+    let mut map: HashMap<String, usize> = HashMap::new();
+    for (i, k) in keys.iter().enumerate() {
+        map.insert(String::from(*k), i);
+    }
+    for (i, k) in keys.iter().enumerate() {
+        let key = IString::from(*k);
+        assert_eq!(hash(&key), hash(&String::from(*k)));
+        assert_eq!(map.get(key.as_str()), Some(&i));
+        assert_eq!(map.get(&*SmallString::from(*k)), Some(&i));
+    }
+    assert_eq!(map.get(IString::from("missing").as_str()), None);
+
+    // and the other way around, `IString` keys looked up by `str`
+    let map: HashMap<IString, usize> = keys.iter().map(|&k| (IString::from(k), k.len())).collect();
+    for k in keys {
+        assert_eq!(map.get(k), Some(&k.len()));
+        assert_eq!(map.get(String::from(k).as_str()), Some(&k.len()));
+    }
+}
+
+#[cfg(feature="std")]
+#[test]
+fn test_as_ref_os_str() {
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    let s = IString::from("some/path");
+    let os: &OsStr = s.as_ref();
+    assert_eq!(os, "some/path");
+    assert_eq!(Path::new(&s), Path::new("some/path"));
+    let s = TinyString::new("path").unwrap();
+    assert_eq!(AsRef::<OsStr>::as_ref(&s), "path");
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use istring::{HashedIString, IString};
+
+#[test]
+fn test_hashed_istring() {
+    let build = RandomState::new();
+    for text in ["", "abc", "a string that is long enough for the heap"] {
+        let h = HashedIString::new(IString::from(text), &build);
+        assert_eq!(h.hash_value(), build.hash_one(text));
+        assert_eq!(h.as_str(), text);
+
+        // only the cached value is fed to the hasher
+        assert_eq!(build.hash_one(&h), build.hash_one(build.hash_one(text)));
+    }
+
+    let a = HashedIString::new(IString::from("abc"), &build);
+    let b = HashedIString::new(IString::from("abc"), &build);
+    let c = HashedIString::new(IString::from("abd"), &build);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let set: HashSet<HashedIString> = [a.clone(), b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&a));
+    assert_eq!(a.into_inner(), "abc");
+}
+
+#[test]
+fn test_different_hashers_compare_unequal() {
+    let a = HashedIString::new(IString::from("abc"), &RandomState::new());
+    let b = HashedIString::new(IString::from("abc"), &RandomState::new());
+    // the two random states hash "abc" differently, so the values are not equal
+    // even though the content is
+    assert_ne!(a.hash_value(), b.hash_value());
+    assert_ne!(a, b);
+    assert_eq!(a.as_str(), b.as_str());
+}
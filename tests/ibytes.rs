@@ -0,0 +1,346 @@
+use istring::IBytes;
+
+#[test]
+fn test_ibytes_vec_methods() {
+    let mut b = IBytes::new();
+    b.push(1);
+    b.extend_from_slice(&[2, 3]);
+    assert_eq!(b, [1, 2, 3][..]);
+    assert!(b.is_inline());
+
+    // crosses the inline -> heap boundary
+    b.extend_from_slice(&[0xff; 30]);
+    assert!(!b.is_inline());
+    assert_eq!(b.len(), 33);
+    assert_eq!(b[.. 4], [1, 2, 3, 0xff]);
+
+    assert_eq!(b.pop(), Some(0xff));
+    b.truncate(2);
+    assert_eq!(b, [1, 2][..]);
+    b.truncate(10);
+    assert_eq!(b, [1, 2][..]);
+
+    let cap = b.capacity();
+    b.clear();
+    assert_eq!(b.len(), 0);
+    assert_eq!(b.capacity(), cap);
+    assert_eq!(b.pop(), None);
+}
+
+#[test]
+fn test_ibytes_from_iter() {
+    let b: IBytes = (0u8 .. 100).collect();
+    assert_eq!(b, (0u8 .. 100).collect::<Vec<u8>>()[..]);
+    assert!(!b.is_inline());
+
+    let mut b: IBytes = [1u8, 2].into_iter().collect();
+    b.extend(&[3u8, 4]);
+    b.extend([&[5u8, 6][..], &[7]]);
+    assert_eq!(b, [1, 2, 3, 4, 5, 6, 7][..]);
+    assert!(b.is_inline());
+}
+
+#[test]
+fn test_ibytes_vec_roundtrip() {
+    let v = vec![0xab; 100];
+    let ptr = v.as_ptr();
+    let b = IBytes::from(v);
+    assert_eq!(b.as_slice().as_ptr(), ptr);
+    let v: Vec<u8> = b.into();
+    assert_eq!(v.as_ptr(), ptr);
+    assert_eq!(v, [0xab; 100]);
+
+    let v: Vec<u8> = IBytes::from(&b"inline"[..]).into();
+    assert_eq!(v, b"inline");
+    assert_eq!(Vec::from(IBytes::new()), b"");
+}
+
+#[test]
+fn test_ibytes_amortized_push() {
+    let mut b = IBytes::new();
+    let mut ptr = b.as_ptr();
+    let mut moves = 0;
+    for i in 0 .. 1000 {
+        b.push(i as u8);
+        if b.as_ptr() != ptr {
+            ptr = b.as_ptr();
+            moves += 1;
+        }
+    }
+    assert!(moves <= 12, "{} reallocations", moves);
+}
+
+#[test]
+fn test_ibytes_reserve_exact_and_shrink() {
+    let mut b = IBytes::from(&[1u8; 30][..]);
+    b.reserve_exact(70);
+    assert_eq!(b.capacity(), 100);
+
+    b.shrink();
+    assert_eq!(b.capacity(), 30);
+    b.truncate(3);
+    b.shrink();
+    assert!(b.is_inline());
+    assert_eq!(b, [1, 1, 1][..]);
+}
+
+#[test]
+fn test_ibytes_to_str() {
+    use istring::{SmallBytes, TinyBytes};
+
+    let long = "ä string that is long enough for the heap";
+    for text in ["", "abc", "äöü", long] {
+        let b = IBytes::from(text.as_bytes());
+        assert_eq!(b.to_str(), Ok(text));
+        assert_eq!(unsafe { b.as_str_unchecked() }, text);
+        assert_eq!(SmallBytes::from(text.as_bytes()).to_str(), Ok(text));
+    }
+    assert_eq!(TinyBytes::new("äö".as_bytes()).unwrap().to_str(), Ok("äö"));
+
+    let invalid = [b'a', 0xc3, b'b'];
+    let err = IBytes::from(&invalid[..]).to_str().unwrap_err();
+    assert_eq!(err.valid_up_to(), 1);
+    assert!(SmallBytes::from(&invalid[..]).to_str().is_err());
+    assert!(TinyBytes::new(&invalid).unwrap().to_str().is_err());
+
+    let mut b = IBytes::from(long.as_bytes());
+    b.push(0xff);
+    assert_eq!(b.to_str().unwrap_err().valid_up_to(), long.len());
+}
+
+#[test]
+fn test_ibytes_string_roundtrip() {
+    use istring::IString;
+
+    let long = "a string that is long enough for the heap";
+    let s = IString::from(long);
+    let ptr = s.as_ptr();
+    let b = IBytes::from(s);
+    assert_eq!(b.as_slice(), long.as_bytes());
+    assert_eq!(b.as_ptr(), ptr);
+    let s = b.into_string().unwrap();
+    assert_eq!(s, long);
+    assert_eq!(s.as_ptr(), ptr);
+
+    let b = IString::from("abc").into_ibytes();
+    assert!(b.is_inline());
+    assert_eq!(b.into_string().unwrap(), "abc");
+
+    let err = IBytes::from(&[b'a', 0xff][..]).into_string().unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 1);
+    assert_eq!(err.into_bytes().as_slice(), &[b'a', 0xff]);
+}
+
+#[test]
+fn test_bytes_ord() {
+    use istring::{SmallBytes, TinyBytes};
+
+    let mut inputs: Vec<&[u8]> = vec![
+        b"",
+        b"\xff",
+        b"\xff\x00",
+        b"\x00",
+        b"a",
+        b"ab",
+        b"\x80abc",
+        b"\xc3",
+        b"a byte string long enough for the heap",
+        b"a byte string long enough for the heap\xfe",
+    ];
+    inputs.sort();
+
+    let mut i: Vec<IBytes> = inputs.iter().rev().map(|&b| IBytes::from(b)).collect();
+    i.sort();
+    assert!(i.iter().map(|b| b.as_slice()).eq(inputs.iter().copied()));
+
+    let mut s: Vec<SmallBytes> = inputs.iter().rev().map(|&b| SmallBytes::from(b)).collect();
+    s.sort();
+    assert!(s.iter().map(|b| b.as_slice()).eq(inputs.iter().copied()));
+
+    let short: Vec<&[u8]> = inputs.iter().copied().filter(|b| b.len() <= 7).collect();
+    let mut t: Vec<TinyBytes> = short.iter().rev().map(|&b| TinyBytes::new(b).unwrap()).collect();
+    t.sort();
+    assert!(t.iter().map(|b| b.as_bytes()).eq(short.iter().copied()));
+
+    let (a, b) = (IBytes::from(&b"\xff"[..]), IBytes::from(&b"\x7f\xff"[..]));
+    assert!(a > b);
+    assert!(TinyBytes::new(b"\xff").unwrap() > TinyBytes::new(b"\x00\xff").unwrap());
+}
+
+#[test]
+fn test_reserve_within_capacity() {
+    use istring::IString;
+
+    let mut s = IString::from("a string that is long enough to live on the heap");
+    s.reserve(100);
+    let cap = s.capacity();
+    let ptr = s.as_ptr();
+    assert!(cap >= s.len() + 100);
+
+    s.reserve(100);
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), cap);
+
+    s.reserve(cap - s.len());
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), cap);
+
+    let mut b = IBytes::from(&b"inline"[..]);
+    b.reserve(10);
+    assert!(b.is_inline());
+    b.reserve(100);
+    assert!(!b.is_inline());
+    assert!(b.capacity() >= 106);
+}
+
+#[test]
+fn test_reserve_exact_within_capacity() {
+    let mut b = IBytes::with_capacity(100);
+    b.extend_from_slice(&[1; 30]);
+    let ptr = b.as_ptr();
+    b.reserve_exact(5);
+    assert_eq!(b.capacity(), 100);
+    assert_eq!(b.as_ptr(), ptr);
+    b.reserve_exact(70);
+    assert_eq!(b.capacity(), 100);
+    assert_eq!(b.as_ptr(), ptr);
+    b.reserve_exact(71);
+    assert_eq!(b.capacity(), 101);
+
+    let mut b = IBytes::from(&b"abc"[..]);
+    b.reserve_exact(2);
+    assert!(b.is_inline());
+    b.reserve_exact(IBytes::INLINE_CAPACITY - 3);
+    assert!(b.is_inline());
+    b.reserve_exact(IBytes::INLINE_CAPACITY);
+    assert!(!b.is_inline());
+    assert_eq!(b.capacity(), IBytes::INLINE_CAPACITY + 3);
+    assert_eq!(b, b"abc"[..]);
+}
+
+#[test]
+fn test_ibytes_resize_and_fill() {
+    let mut b = IBytes::from(&b"abc"[..]);
+    b.resize(10, b'x');
+    assert!(b.is_inline());
+    assert_eq!(b, b"abcxxxxxxx"[..]);
+
+    b.resize(40, 0);
+    assert!(!b.is_inline());
+    assert_eq!(b.len(), 40);
+    assert_eq!(b[..10], b"abcxxxxxxx"[..]);
+    assert!(b[10..].iter().all(|&c| c == 0));
+
+    b.fill(7);
+    assert!(b.iter().all(|&c| c == 7));
+
+    b.resize(2, 1);
+    assert_eq!(b, [7, 7][..]);
+
+    let mut b = IBytes::new();
+    b.resize(5, 1);
+    b.fill(9);
+    b.resize(3, 0);
+    assert_eq!(b, [9, 9, 9][..]);
+    // the bytes past the end are cleared when truncating inline
+    b.resize(5, 0);
+    assert_eq!(b, [9, 9, 9, 0, 0][..]);
+}
+
+#[test]
+fn test_ibytes_spare_capacity_mut() {
+    let mut b = IBytes::from(&b"ab"[..]);
+    let cap = b.capacity();
+    let spare = b.spare_capacity_mut();
+    assert_eq!(spare.len(), cap - 2);
+    spare[0].write(b'c');
+    spare[1].write(b'd');
+    unsafe { b.set_len(4) };
+    assert_eq!(b, b"abcd"[..]);
+    assert!(b.is_inline());
+
+    let mut b = IBytes::with_capacity(100);
+    b.extend_from_slice(b"header:");
+    let ptr = b.as_ptr();
+    let src = [b'x'; 50];
+    let spare = b.spare_capacity_mut();
+    assert!(spare.len() >= 93);
+    for (dst, &byte) in spare.iter_mut().zip(src.iter()) {
+        dst.write(byte);
+    }
+    unsafe { b.set_len(7 + 50) };
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(b[..7], b"header:"[..]);
+    assert!(b[7..].iter().all(|&c| c == b'x'));
+}
+
+#[test]
+fn test_ibytes_spare_capacity_keeps_zero_tail() {
+    let mut b = IBytes::from(&b"ab"[..]);
+    for dst in b.spare_capacity_mut() {
+        dst.write(b'x');
+    }
+    unsafe { b.set_len(3) };
+    assert_eq!(b, b"abx"[..]);
+    assert_eq!(b.inline_bytes(), IBytes::from(&b"abx"[..]).inline_bytes());
+    assert!(b.inline_bytes().unwrap()[3 ..].iter().all(|&c| c == 0));
+}
+
+#[test]
+fn test_bytes_eq_slices() {
+    use istring::{SmallBytes, TinyBytes};
+    let slice: &[u8] = &[1, 2, 3];
+
+    let b = IBytes::from(vec![1, 2, 3]);
+    assert!(b == [1u8, 2, 3][..]);
+    assert!([1u8, 2, 3][..] == b);
+    assert!(b == slice);
+    assert!(slice == b);
+    assert!(b == vec![1u8, 2, 3]);
+    assert!(vec![1u8, 2, 3] == b);
+    assert!(b != vec![1u8, 2]);
+
+    let long = vec![9u8; 40];
+    let b = IBytes::from(long.clone());
+    assert!(b == long);
+    assert!(long == b);
+
+    let s = SmallBytes::from(&[1u8, 2, 3][..]);
+    assert!(s == [1u8, 2, 3][..]);
+    assert!([1u8, 2, 3][..] == s);
+    assert!(s == slice);
+    assert!(slice == s);
+    assert!(s == vec![1u8, 2, 3]);
+    assert!(vec![1u8, 2, 3] == s);
+
+    let t = TinyBytes::new(&[1, 2, 3]).unwrap();
+    assert!(t == [1u8, 2, 3][..]);
+    assert!([1u8, 2, 3][..] == t);
+    assert!(t == slice);
+    assert!(slice == t);
+    assert!(t == vec![1u8, 2, 3]);
+    assert!(vec![1u8, 2, 3] == t);
+}
+
+#[test]
+fn test_ibytes_as_chunks() {
+    let b = IBytes::from(&[0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9][..]);
+    let (chunks, rest) = b.as_chunks::<4>();
+    assert_eq!(chunks, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+    assert_eq!(rest, [8, 9]);
+
+    let (chunks, rest) = b.as_chunks::<5>();
+    assert_eq!(chunks.len(), 2);
+    assert!(rest.is_empty());
+
+    let (chunks, rest) = b.as_chunks::<16>();
+    assert!(chunks.is_empty());
+    assert_eq!(rest.len(), 10);
+
+    let records: Vec<u8> = (0 .. 100).collect();
+    let b = IBytes::from(records);
+    let (chunks, rest) = b.as_chunks::<8>();
+    assert_eq!(chunks.len(), 12);
+    assert_eq!(chunks[11], [88, 89, 90, 91, 92, 93, 94, 95]);
+    assert_eq!(rest, [96, 97, 98, 99]);
+}
@@ -1,4 +1,4 @@
-use istring::{IString, SmallString};
+use istring::{IString, SmallString, TinyString};
 
 #[test]
 fn test_misc_istring() {
@@ -28,3 +28,1298 @@ fn test_misc_smallstring() {
     let s2 = SmallString::from(p2);
     assert_eq!(s2, p2);
 }
+
+#[test]
+fn test_make_ascii_case() {
+    let p = "Hello Wörld! This Is A Heap Sized String";
+
+    let mut s = IString::from(p);
+    s.make_ascii_uppercase();
+    assert_eq!(s, p.to_ascii_uppercase());
+    s.make_ascii_lowercase();
+    assert_eq!(s, p.to_ascii_lowercase());
+
+    let mut s = SmallString::from(p);
+    s.make_ascii_uppercase();
+    assert_eq!(s, p.to_ascii_uppercase());
+    s.make_ascii_lowercase();
+    assert_eq!(s, p.to_ascii_lowercase());
+
+    let mut s = TinyString::new("AbÖ").unwrap();
+    s.make_ascii_uppercase();
+    assert_eq!(s, "ABÖ");
+    s.make_ascii_lowercase();
+    assert_eq!(s, "abÖ");
+}
+
+#[test]
+fn test_to_ascii_case() {
+    for p in ["Hello Wörld!", "Hello Wörld! This Is A Heap Sized String"] {
+        let s = IString::from(p);
+        let upper = s.to_ascii_uppercase();
+        assert_eq!(s, p);
+        assert_eq!(upper, p.to_ascii_uppercase());
+        assert_eq!(s.to_ascii_lowercase(), p.to_ascii_lowercase());
+
+        let s = SmallString::from(p);
+        assert_eq!(s.to_ascii_uppercase(), p.to_ascii_uppercase());
+        assert_eq!(s.to_ascii_lowercase(), p.to_ascii_lowercase());
+        assert_eq!(s, p);
+    }
+
+    let s = TinyString::new("AbÖ").unwrap();
+    assert_eq!(s.to_ascii_uppercase(), "ABÖ");
+    assert_eq!(s.to_ascii_lowercase(), "abÖ");
+    assert_eq!(s, "AbÖ");
+}
+
+#[test]
+fn test_repeat() {
+    let s = IString::from("-=");
+    assert_eq!(s.repeat(0), "");
+    assert_eq!(s.repeat(3), "-=-=-=");
+    let r = s.repeat(20);
+    assert_eq!(r, "-=".repeat(20));
+    assert!(r.capacity() >= 40);
+
+    let s = SmallString::from("-=");
+    assert_eq!(s.repeat(3), "-=-=-=");
+    assert_eq!(s.repeat(20), "-=".repeat(20));
+}
+
+#[test]
+#[should_panic]
+fn test_repeat_overflow() {
+    let _ = IString::from("ab").repeat(usize::MAX);
+}
+
+#[test]
+fn test_replace() {
+    let cases = [
+        ("hello world", "o", "0"),
+        ("aaaa", "aa", "b"),
+        ("aaa", "aa", "xyz"),
+        ("abc", "", "-"),
+        ("", "", "-"),
+        ("héllo wörld, this one is long enough for the heap", "ö", "oe"),
+        ("nothing to see", "xyz", "abc"),
+    ];
+    for (s, from, to) in cases {
+        let i = IString::from(s);
+        assert_eq!(i.replace(from, to), s.replace(from, to));
+        for n in 0 .. 4 {
+            assert_eq!(i.replacen(from, to, n), s.replacen(from, to, n));
+        }
+    }
+}
+
+#[test]
+fn test_iterators() {
+    let p = "Hello Wörld! This one is long enough for the heap";
+
+    let s = IString::from(p);
+    assert!(s.chars().eq(p.chars()));
+    assert!(s.char_indices().eq(p.char_indices()));
+    assert!(s.bytes().eq(p.bytes()));
+
+    let s = SmallString::from(p);
+    assert!(s.chars().eq(p.chars()));
+    assert!(s.char_indices().eq(p.char_indices()));
+    assert!(s.bytes().eq(p.bytes()));
+
+    let s = TinyString::new("wö").unwrap();
+    assert!(s.chars().eq("wö".chars()));
+    assert!(s.char_indices().eq("wö".char_indices()));
+    assert!(s.bytes().eq("wö".bytes()));
+}
+
+#[test]
+fn test_iterator_lifetime() {
+    let s = IString::from("ä long string that lives on the heap");
+    let iter = s.char_indices();
+    let collected: Vec<_> = iter.collect();
+    assert_eq!(collected[1], (2, ' '));
+}
+
+#[test]
+fn test_leak() {
+    let s: &'static str = IString::from("short").leak();
+    assert_eq!(s, "short");
+
+    let s: &'static mut str = IString::from("Hello World! .........xyz").leak();
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HELLO WORLD! .........XYZ");
+
+    assert_eq!(IString::new().leak(), "");
+}
+
+#[test]
+fn test_from_utf16() {
+    // 𝄞music
+    let v = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
+    assert_eq!(IString::from_utf16(&v).unwrap(), "𝄞music");
+    assert_eq!(IString::from_utf16_lossy(&v), "𝄞music");
+
+    // 𝄞mu<invalid>ic
+    let v = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
+    assert!(IString::from_utf16(&v).is_err());
+    assert_eq!(IString::from_utf16_lossy(&v), String::from_utf16_lossy(&v));
+
+    let long: Vec<u16> = "a long string that ends up on the heap".encode_utf16().collect();
+    assert_eq!(IString::from_utf16(&long).unwrap(), "a long string that ends up on the heap");
+}
+
+#[test]
+fn test_from_utf8_lossy() {
+    let cases: [&[u8]; 5] = [
+        b"Hello World",
+        b"Hello \xF0\x90\x80World",
+        b"\xff\xfe",
+        b"Hello World! this one ends up on the heap \xC3",
+        b"",
+    ];
+    for bytes in cases {
+        assert_eq!(IString::from_utf8_lossy(bytes), *String::from_utf8_lossy(bytes));
+    }
+}
+
+#[cfg(target_pointer_width="64")]
+const _: () = {
+    assert!(IString::INLINE_CAPACITY == 23);
+    assert!(istring::IBytes::INLINE_CAPACITY == 23);
+    assert!(SmallString::INLINE_CAPACITY == 15);
+    assert!(istring::SmallBytes::INLINE_CAPACITY == 15);
+};
+const _: () = assert!(TinyString::INLINE_CAPACITY == 7);
+
+#[test]
+fn test_inline_capacity() {
+    let s = "x".repeat(IString::INLINE_CAPACITY);
+    assert_eq!(IString::from(s.as_str()).capacity(), IString::INLINE_CAPACITY);
+    assert!(TinyString::new(&s[.. TinyString::INLINE_CAPACITY]).is_some());
+    assert!(TinyString::new(&s[.. TinyString::INLINE_CAPACITY + 1]).is_none());
+}
+
+#[test]
+fn test_heap_capacity() {
+    let s = IString::from("short");
+    assert_eq!(s.heap_capacity(), None);
+    let s = IString::from("Hello World! .........xyz");
+    assert!(s.heap_capacity().unwrap() >= s.len());
+    let s = IString::from(String::with_capacity(100));
+    assert_eq!(s.heap_capacity(), Some(100));
+
+    assert_eq!(SmallString::from("short").heap_capacity(), None);
+    assert_eq!(SmallString::from("Hello World! .........xyz").heap_capacity(), Some(25));
+
+    let b = istring::IBytes::with_capacity(64);
+    assert!(b.heap_capacity().unwrap() >= 64);
+}
+
+#[test]
+fn test_inline_bytes() {
+    let mut s = IString::from("Hello World!");
+    let bytes = s.inline_bytes().unwrap();
+    assert_eq!(&bytes[.. s.len()], b"Hello World!");
+    assert!(bytes[s.len() ..].iter().all(|&b| b == 0));
+
+    s.truncate(5);
+    assert_eq!(s.inline_bytes().unwrap()[.. 12], *b"Hello\0\0\0\0\0\0\0");
+    assert_eq!(s.inline_bytes(), IString::from("Hello").inline_bytes());
+
+    let mut s = IString::from("Hello World! .........xyz");
+    assert_eq!(s.inline_bytes(), None);
+    s.truncate(5);
+    s.shrink();
+    assert_eq!(s.inline_bytes(), IString::from("Hello").inline_bytes());
+}
+
+#[test]
+fn test_zeroed_tail() {
+    fn assert_zero_tail(s: &IString) {
+        assert!(s.inline_bytes().unwrap()[s.len() ..].iter().all(|&b| b == 0));
+    }
+    let mut s = IString::from("abcdef");
+    s.truncate(3);
+    assert_eq!(s, "abc");
+    assert_zero_tail(&s);
+
+    let mut s = IString::from("abcdé");
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s, "abcd");
+    assert_zero_tail(&s);
+
+    assert_eq!(s.remove(1), 'b');
+    assert_eq!(s, "acd");
+    assert_zero_tail(&s);
+
+    assert_eq!(s.remove(2), 'd');
+    assert_eq!(s.pop(), Some('c'));
+    assert_eq!(s.pop(), Some('a'));
+    assert_eq!(s.pop(), None);
+    assert_eq!(s.inline_bytes(), IString::new().inline_bytes());
+}
+
+#[test]
+fn test_remove_heap() {
+    let mut s = IString::from("Hello Wörld! .........xyz");
+    assert_eq!(s.remove(7), 'ö');
+    assert_eq!(s, "Hello Wrld! .........xyz");
+    assert_eq!(s.pop(), Some('z'));
+    assert_eq!(s, "Hello Wrld! .........xy");
+}
+
+#[test]
+fn test_truncate_chars() {
+    let mut s = IString::from("äöü€𝄞 and some more text for the heap");
+    s.truncate_chars(100);
+    assert_eq!(s, "äöü€𝄞 and some more text for the heap");
+    s.truncate_chars(5);
+    assert_eq!(s, "äöü€𝄞");
+    s.truncate_chars(2);
+    assert_eq!(s, "äö");
+    s.truncate_chars(0);
+    assert_eq!(s, "");
+}
+
+#[test]
+#[should_panic]
+fn test_truncate_char_boundary() {
+    let mut s = IString::from("é");
+    s.truncate(1);
+}
+
+#[test]
+fn test_split_at() {
+    let p = "Hello Wörld! .........xyz";
+    let s = IString::from(p);
+    assert_eq!(s.split_at(0), ("", p));
+    assert_eq!(s.split_at(6), ("Hello ", "Wörld! .........xyz"));
+    assert_eq!(s.split_at(p.len()), (p, ""));
+    assert_eq!(SmallString::from(p).split_at(9), ("Hello Wö", "rld! .........xyz"));
+    assert_eq!(TinyString::new("aöb").unwrap().split_at(3), ("aö", "b"));
+}
+
+#[test]
+#[should_panic]
+fn test_split_at_char_boundary() {
+    IString::from("Hello Wörld! .........xyz").split_at(8);
+}
+
+#[test]
+fn test_extend_from_within() {
+    let mut s = IString::from("abcö");
+    s.extend_from_within(..);
+    assert_eq!(s, "abcöabcö");
+    s.extend_from_within(3 ..= 4);
+    assert_eq!(s, "abcöabcöö");
+    assert!(s.inline_bytes().is_some());
+
+    // crosses the inline -> heap boundary
+    s.extend_from_within(1 .. 10);
+    assert_eq!(s, "abcöabcööbcöabcö");
+    s.extend_from_within(..);
+    assert_eq!(s, "abcöabcööbcöabcöabcöabcööbcöabcö");
+    assert!(s.inline_bytes().is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_extend_from_within_char_boundary() {
+    IString::from("abcö").extend_from_within(4 ..);
+}
+
+#[test]
+fn test_push_amortized_growth() {
+    let mut s = IString::new();
+    let mut ptr = s.as_ptr();
+    let mut moves = 0;
+    for i in 0 .. 10_000 {
+        write!(s, "{}", i % 10).unwrap();
+        if s.as_ptr() != ptr {
+            ptr = s.as_ptr();
+            moves += 1;
+        }
+    }
+    assert_eq!(s.len(), 10_000);
+    // log2(10_000) ~ 14
+    assert!(moves <= 16, "{} reallocations", moves);
+}
+
+#[test]
+fn test_push_str_sequence() {
+    let mut s = IString::new();
+    let mut reference = String::new();
+    for i in 0 .. 200 {
+        let part = &"äbc defg"[.. i % 9 / 2 * 2];
+        s.push_str(part);
+        reference.push_str(part);
+        assert_eq!(s, reference);
+        assert_eq!(s.len(), reference.len());
+    }
+}
+
+// informational, run with `cargo test --release -- --nocapture bench_`
+#[test]
+fn bench_push_str() {
+    let start = std::time::Instant::now();
+    let mut total = 0;
+    for _ in 0 .. 1000 {
+        let mut s = IString::new();
+        for _ in 0 .. 100 {
+            s.push_str("abc");
+        }
+        total += s.len();
+    }
+    assert_eq!(total, 300_000);
+    println!("100k push_str: {:?}", start.elapsed());
+}
+
+#[test]
+fn test_heap_backed() {
+    let string = String::from("short");
+    let ptr = string.as_ptr();
+    let mut s = unsafe { IString::from_string_unchecked(string) };
+    assert!(!s.is_inline());
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s, "short");
+    s.push_str("er");
+    assert_eq!(s, "shorter");
+
+    let s = IString::from("short");
+    assert!(s.is_inline());
+    let s = s.into_heap_backed();
+    assert!(!s.is_inline());
+    assert_eq!(s, "short");
+
+    let s = IString::from("Hello World! .........xyz");
+    let ptr = s.as_ptr();
+    let s = s.into_heap_backed();
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut s = IString::from("short");
+    s.try_reserve(10).unwrap();
+    assert!(s.is_inline());
+    s.try_reserve(100).unwrap();
+    assert!(s.capacity() >= 105);
+    assert_eq!(s, "short");
+    let cap = s.capacity();
+    s.try_reserve_exact(cap).unwrap();
+    assert!(s.capacity() >= cap + 5);
+
+    // the fallible and infallible versions agree
+    for n in [0, 3, 50, 500] {
+        let mut a = s.clone();
+        let mut b = s.clone();
+        a.reserve_exact(n);
+        b.try_reserve_exact(n).unwrap();
+        assert_eq!(a.capacity(), b.capacity());
+        assert_eq!(a.is_inline(), b.is_inline());
+    }
+    let mut a = IString::from("abc");
+    let mut b = a.clone();
+    a.reserve_exact(2);
+    b.try_reserve_exact(2).unwrap();
+    assert!(a.is_inline() && b.is_inline());
+
+    assert!(s.try_reserve(usize::MAX).is_err());
+    assert!(s.try_reserve(isize::MAX as usize).is_err());
+    assert_eq!(s, "short");
+
+    let mut s = IString::from("short");
+    assert!(s.try_reserve(usize::MAX).is_err());
+    assert!(s.try_reserve_exact(usize::MAX - 2).is_err());
+    assert!(s.is_inline());
+    assert_eq!(s, "short");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // miri aborts on the huge allocation instead of failing it
+fn test_try_with_capacity() {
+    let s = IString::try_with_capacity(10).unwrap();
+    assert!(s.is_inline());
+    let s = IString::try_with_capacity(100).unwrap();
+    assert!(s.capacity() >= 100);
+    assert_eq!(s, "");
+    assert!(IString::try_with_capacity(usize::MAX).is_err());
+    assert!(IString::try_with_capacity(isize::MAX as usize).is_err());
+}
+
+#[test]
+fn test_push_str_inline_boundary() {
+    let cap = IString::INLINE_CAPACITY;
+
+    // fill up to exactly the inline capacity with a multi-byte char at the end
+    let mut s = IString::new();
+    s.push_str(&"a".repeat(cap - 3));
+    s.push('€');
+    assert_eq!(s.len(), cap);
+    assert!(s.is_inline());
+    assert!(s.ends_with('€'));
+
+    // one more byte moves it to the heap
+    s.push('b');
+    assert!(!s.is_inline());
+    assert_eq!(s.len(), cap + 1);
+    assert_eq!(s.as_str(), format!("{}€b", "a".repeat(cap - 3)));
+
+    let mut s = IString::new();
+    for _ in 0 .. cap {
+        s.push_str("x");
+        assert!(s.is_inline());
+    }
+    s.push_str("");
+    assert!(s.is_inline());
+    assert_eq!(s, "x".repeat(cap).as_str());
+}
+
+#[test]
+fn test_trimmed() {
+    for &(input, expected) in &[
+        ("  abc \n", "abc"),
+        ("\tabc", "abc"),
+        ("abc  ", "abc"),
+        ("abc", "abc"),
+        ("   ", ""),
+        ("  a long string that does not fit inline  ", "a long string that does not fit inline"),
+        ("\u{3000}ä ö\u{3000}", "ä ö"),
+    ] {
+        let s = IString::from(input);
+        assert_eq!(s.trimmed(), expected);
+        let was_inline = s.is_inline();
+        let t = s.into_trimmed();
+        assert_eq!(t, expected);
+        assert_eq!(t.is_inline(), was_inline);
+    }
+}
+
+#[test]
+fn test_display_padding() {
+    assert_eq!(format!("{:>5}", IString::from("ab")), "   ab");
+    assert_eq!(format!("{:<5}|", IString::from("ab")), "ab   |");
+    assert_eq!(format!("{:*^6}", SmallString::from("ab")), "**ab**");
+    assert_eq!(format!("{:.2}", IString::from("abcdef")), "ab");
+    assert_eq!(format!("{:>4.2}", IString::from("äöü")), "  äö");
+}
+
+#[test]
+fn test_debug_alternate() {
+    let s = IString::from("abc");
+    assert_eq!(format!("{:?}", s), "\"abc\"");
+    assert_eq!(
+        format!("{:#?}", s),
+        format!("IString {{\n    inline: true,\n    len: 3,\n    cap: {},\n    str: \"abc\",\n}}", IString::INLINE_CAPACITY)
+    );
+
+    let long = "a".repeat(40);
+    let mut s = IString::with_capacity(64);
+    s.push_str(&long);
+    assert_eq!(format!("{:?}", s), format!("{:?}", long));
+    let debug = format!("{:#?}", s);
+    assert!(debug.starts_with("IString {\n    inline: false,\n    len: 40,\n"));
+    assert!(debug.contains(&format!("cap: {},", s.capacity())));
+
+    let s = SmallString::from("x");
+    assert_eq!(format!("{:?}", s), "\"x\"");
+    assert!(format!("{:#?}", s).starts_with("SmallString {\n    inline: true,\n    len: 1,\n"));
+}
+
+#[test]
+fn test_const_macros() {
+    const EMPTY: IString = istring::istring!("");
+    const ABC: IString = istring::istring!("abc");
+    static TINY: TinyString = istring::tiny!("ä7");
+    assert_eq!(EMPTY, "");
+    assert_eq!(ABC, "abc");
+    assert!(ABC.is_inline());
+    assert_eq!(TINY, "ä7");
+
+    let full = istring::istring!("abcdefghijk");
+    assert_eq!(full, "abcdefghijk");
+    assert_eq!(istring::tiny!("1234567"), "1234567");
+
+    let mut s = ABC;
+    s.push_str(" and a lot more that goes to the heap");
+    assert_eq!(s, "abc and a lot more that goes to the heap");
+    assert_eq!(ABC, "abc");
+
+    assert!(IString::new_inline(&"x".repeat(IString::INLINE_CAPACITY + 1)).is_none());
+}
+
+#[test]
+fn test_as_ptr() {
+    use std::slice;
+
+    for text in ["abc", "a string that is long enough for the heap"] {
+        let mut s = IString::from(text);
+        let bytes = unsafe { slice::from_raw_parts(s.as_ptr(), s.len()) };
+        assert_eq!(bytes, text.as_bytes());
+
+        unsafe { *s.as_mut_ptr() = b'X' };
+        assert_eq!(&s[1 ..], &text[1 ..]);
+        assert!(s.starts_with('X'));
+
+        let s = SmallString::from(text);
+        let bytes = unsafe { slice::from_raw_parts(s.as_ptr(), s.len()) };
+        assert_eq!(bytes, text.as_bytes());
+    }
+
+    // heap-backed pointers survive moves
+    let s = IString::from("a string that is long enough for the heap");
+    let ptr = s.as_ptr();
+    let moved = s;
+    assert_eq!(moved.as_ptr(), ptr);
+}
+
+#[test]
+fn test_inline_or_heap() {
+    use istring::ibytes::{IBytes, InlineOrHeap, InlineOrHeapRef};
+
+    let s = IString::from("abc");
+    match s.as_inline_or_heap() {
+        InlineOrHeapRef::Inline(bytes) => assert_eq!(bytes, b"abc"),
+        InlineOrHeapRef::Heap(_) => panic!("expected inline"),
+    }
+    match s.into_inline_or_heap() {
+        InlineOrHeap::Inline(inline) => {
+            assert_eq!(inline.len, 3);
+            assert_eq!(&inline.data[.. 3], b"abc");
+        }
+        InlineOrHeap::Heap(_) => panic!("expected inline"),
+    }
+
+    let text = "a string that is long enough for the heap";
+    let s = IString::from(text);
+    let ptr = s.as_ptr();
+    match s.as_inline_or_heap() {
+        InlineOrHeapRef::Heap(heap) => {
+            assert_eq!(heap.len, text.len());
+            assert_eq!(heap.ptr as *const u8, ptr);
+        }
+        InlineOrHeapRef::Inline(_) => panic!("expected heap"),
+    }
+    match s.into_inline_or_heap() {
+        InlineOrHeap::Heap(heap) => {
+            assert_eq!(heap.len, text.len());
+            // hand the allocation back so it gets freed
+            let bytes = unsafe { IBytes::from_heap(heap) };
+            assert_eq!(bytes.as_slice(), text.as_bytes());
+        }
+        InlineOrHeap::Inline(_) => panic!("expected heap"),
+    }
+}
+
+#[test]
+fn test_cross_type_eq() {
+    use istring::{IBytes, SmallBytes, TinyBytes};
+
+    let i = IString::from("abc");
+    let s = SmallString::from("abc");
+    let t = TinyString::new("abc").unwrap();
+    assert_eq!(i, s);
+    assert_eq!(s, i);
+    assert_eq!(i, t);
+    assert_eq!(t, i);
+    assert_eq!(s, t);
+    assert_eq!(t, s);
+    assert_ne!(i, SmallString::from("abd"));
+    assert_ne!(TinyString::new("ab").unwrap(), i);
+
+    let long = "a string that is long enough for the heap";
+    assert_eq!(IString::from(long), SmallString::from(long));
+
+    let i = IBytes::from(&b"abc"[..]);
+    let s = SmallBytes::from(&b"abc"[..]);
+    let t = TinyBytes::new(b"abc").unwrap();
+    assert_eq!(i, s);
+    assert_eq!(s, i);
+    assert_eq!(i, t);
+    assert_eq!(t, i);
+    assert_eq!(s, t);
+    assert_eq!(t, s);
+    assert_ne!(i, TinyBytes::new(b"ab").unwrap());
+}
+
+#[test]
+fn test_convert_istring_smallstring() {
+    let long = "a string that is long enough for the heap";
+
+    // tight heap allocation is handed over in both directions
+    let i = IString::from(String::from(long).into_boxed_str().into_string());
+    assert_eq!(i.heap_capacity(), Some(long.len()));
+    let ptr = i.as_ptr();
+    let s = SmallString::from(i);
+    assert_eq!(s, long);
+    assert_eq!(s.as_ptr(), ptr);
+    let i = IString::from(s);
+    assert_eq!(i, long);
+    assert_eq!(i.as_ptr(), ptr);
+
+    // spare capacity has to be released
+    let mut i = IString::with_capacity(64);
+    i.push_str(long);
+    let s = SmallString::from(i);
+    assert_eq!(s, long);
+
+    for text in ["", "abc", "abcdefghijklmnopqrst"] {
+        let s = SmallString::from(IString::from(text));
+        assert_eq!(s, text);
+        assert_eq!(s.heap_capacity().is_none(), text.len() <= SmallString::INLINE_CAPACITY);
+        let i = IString::from(s);
+        assert_eq!(i, text);
+        assert_eq!(i.is_inline(), text.len() <= SmallString::INLINE_CAPACITY);
+    }
+}
+
+#[test]
+fn test_keep() {
+    let text = "a string that is long enough for the heap";
+    let mut s = IString::from(text);
+    let ptr = s.as_ptr();
+    s.keep(2 .. 8);
+    assert_eq!(s, "string");
+    assert_eq!(s.as_ptr(), ptr);
+    assert!(!s.is_inline());
+
+    let mut s = IString::from("äöü");
+    s.keep(2 ..);
+    assert_eq!(s, "öü");
+    s.keep(..= 1);
+    assert_eq!(s, "ö");
+    s.keep(..);
+    assert_eq!(s, "ö");
+    s.keep(2 .. 2);
+    assert_eq!(s, "");
+}
+
+#[test]
+#[should_panic]
+fn test_keep_char_boundary() {
+    IString::from("äöü").keep(1 ..);
+}
+
+#[test]
+fn test_split_owned() {
+    let s = IString::from("a,,b,a longer segment that goes to the heap,");
+    let parts: Vec<IString> = s.split_on(',').collect();
+    assert_eq!(parts, ["a", "", "b", "a longer segment that goes to the heap", ""]);
+    assert!(parts[0].is_inline());
+    assert!(!parts[3].is_inline());
+    assert_eq!(IString::new().split_on(',').collect::<Vec<_>>(), [""]);
+
+    let s = IString::from("one\r\ntwo\n\nthree\n");
+    let lines: Vec<IString> = s.split_lines().collect();
+    assert_eq!(lines, ["one", "two", "", "three"]);
+    assert!(lines.iter().all(|l| l.is_inline()));
+    assert_eq!(IString::new().split_lines().count(), 0);
+}
+
+#[test]
+fn test_collapse_whitespace() {
+    fn reference(s: &str) -> String {
+        s.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+    }
+    for text in [
+        "",
+        "   ",
+        "\t\r\n ",
+        "abc",
+        "  a  b\t\tc  ",
+        "\na\u{3000}b\n",
+        "ä  ö\n\nü",
+        "  a longer string   with\tplenty of\n\n  whitespace that lives on the heap  ",
+    ] {
+        let mut s = IString::from(text);
+        let was_inline = s.is_inline();
+        s.collapse_whitespace();
+        assert_eq!(s, reference(text), "{:?}", text);
+        assert_eq!(s.is_inline(), was_inline);
+    }
+}
+
+#[test]
+fn test_raw_parts() {
+    let s = IString::from("abc");
+    let (ptr, len, cap) = s.as_raw_parts();
+    assert_eq!((len, cap), (3, IString::INLINE_CAPACITY));
+    assert_eq!(unsafe { std::slice::from_raw_parts(ptr, len) }, b"abc");
+
+    // inline strings are moved to the heap when decomposed
+    let (ptr, len, cap) = s.into_raw_parts();
+    let mut s = unsafe { IString::from_raw_parts_full(ptr, len, cap) };
+    assert_eq!(s, "abc");
+    s.shrink();
+    assert!(s.is_inline());
+
+    let text = "a string that is long enough for the heap";
+    let mut s = IString::with_capacity(64);
+    s.push_str(text);
+    let (ptr, len, cap) = s.as_raw_parts();
+    assert_eq!((len, cap), (text.len(), s.capacity()));
+    let (ptr2, len2, cap2) = s.into_raw_parts();
+    assert_eq!((ptr2 as *const u8, len2, cap2), (ptr, len, cap));
+    let s = unsafe { IString::from_raw_parts_full(ptr2, len2, cap2) };
+    assert_eq!(s, text);
+    assert_eq!(s.as_raw_parts(), (ptr, len, cap));
+
+    let (ptr, len, cap) = IString::new().into_raw_parts();
+    assert_eq!(unsafe { IString::from_raw_parts_full(ptr, len, cap) }, "");
+}
+
+#[test]
+fn test_push_bytes_unchecked() {
+    let input = "a ä € 𝄞 and enough text to end up on the heap";
+    let mut s = IString::from("x");
+    s.reserve(input.len());
+    let cap = s.capacity();
+    // push one byte at a time, splitting the multi-byte chars
+    for b in input.bytes() {
+        unsafe { s.push_bytes_unchecked(&[b]) };
+    }
+    assert_eq!(s.capacity(), cap);
+    assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s, format!("x{}", input));
+
+    unsafe { s.set_len_unchecked(1) };
+    assert_eq!(s, "x");
+
+    let mut s = IString::new();
+    unsafe { s.push_bytes_unchecked("ä".as_bytes()) };
+    assert!(s.is_inline());
+    assert_eq!(s, "ä");
+}
+
+#[test]
+fn test_clone_from() {
+    let mut s = IString::with_capacity(100);
+    s.push_str("some previous content that lives on the heap");
+    let ptr = s.as_ptr();
+
+    let source = IString::from("a shorter string but still on the heap");
+    s.clone_from(&source);
+    assert_eq!(s, source);
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), 100);
+
+    // inline sources are copied into the allocation too
+    s.clone_from(&IString::from("abc"));
+    assert_eq!(s, "abc");
+    assert_eq!(s.as_ptr(), ptr);
+
+    // too small, falls back to clone
+    let long = "x".repeat(200);
+    s.clone_from(&IString::from(long.as_str()));
+    assert_eq!(s, long.as_str());
+
+    let mut s = IString::from("inline");
+    s.clone_from(&IString::from("other"));
+    assert_eq!(s, "other");
+    assert!(s.is_inline());
+}
+
+#[test]
+fn test_smallstring_shrink_to_inline() {
+    use istring::SmallBytes;
+    use istring::small::Heap;
+
+    // a short string that still lives in a heap allocation
+    let boxed: Box<[u8]> = Box::from("äbc".as_bytes());
+    let len = boxed.len();
+    let ptr = Box::into_raw(boxed) as *mut u8;
+    let bytes = unsafe { SmallBytes::from_heap(Heap { ptr, len }) };
+    let mut s = SmallString::from_utf8(bytes).unwrap();
+    assert_eq!(s.heap_capacity(), Some(4));
+
+    assert!(s.shrink_to_inline());
+    assert_eq!(s.heap_capacity(), None);
+    assert_eq!(s, "äbc");
+    assert!(s.shrink_to_inline());
+
+    let long = "a string that is long enough for the heap";
+    let mut s = SmallString::from(long);
+    assert!(!s.shrink_to_inline());
+    assert_eq!(s, long);
+}
+
+#[test]
+fn test_extract_if() {
+    let is_vowel = |c: char| "aeiouäöü".contains(c);
+
+    let text = "a string with vowels, äöü, long enough for the heap";
+    let mut s = IString::from(text);
+    let ptr = s.as_ptr();
+    let vowels: String = s.extract_if(is_vowel).collect();
+    assert_eq!(vowels, text.chars().filter(|&c| is_vowel(c)).collect::<String>());
+    assert_eq!(s, text.chars().filter(|&c| !is_vowel(c)).collect::<String>());
+    assert_eq!(s.as_ptr(), ptr);
+
+    let mut s = IString::from("häuser");
+    let vowels: String = s.extract_if(is_vowel).collect();
+    assert_eq!(vowels, "äue");
+    assert_eq!(s, "hsr");
+    assert!(s.is_inline());
+
+    // dropping early keeps the rest
+    let mut s = IString::from("banana split");
+    assert_eq!(s.extract_if(is_vowel).next(), Some('a'));
+    assert_eq!(s, "bnana split");
+
+    // a leaked iterator leaves the chars kept so far
+    let mut s = IString::from("banana");
+    let mut iter = s.extract_if(is_vowel);
+    iter.next();
+    iter.next();
+    std::mem::forget(iter);
+    assert_eq!(s, "bn");
+}
+
+#[test]
+fn test_extract_if_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    for text in ["abcdef", "abcdef and enough text for the heap"] {
+        let mut s = IString::from(text);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            s.extract_if(|c| if c == 'd' { panic!() } else { c == 'b' }).for_each(drop);
+        }));
+        assert!(result.is_err());
+        assert_eq!(s, text.replacen('b', "", 1));
+    }
+}
+
+#[test]
+fn test_char_at() {
+    let s = IString::from("aä€𝄞b");
+    assert_eq!(s.char_at(0), Some('a'));
+    assert_eq!(s.char_at(1), Some('ä'));
+    assert_eq!(s.char_at(3), Some('𝄞'));
+    assert_eq!(s.char_at(4), Some('b'));
+    assert_eq!(s.char_at(5), None);
+
+    assert_eq!(s.byte_offset_of_char(0), Some(0));
+    assert_eq!(s.byte_offset_of_char(1), Some(1));
+    assert_eq!(s.byte_offset_of_char(2), Some(3));
+    assert_eq!(s.byte_offset_of_char(3), Some(6));
+    assert_eq!(s.byte_offset_of_char(4), Some(10));
+    assert_eq!(s.byte_offset_of_char(5), Some(11));
+    assert_eq!(s.byte_offset_of_char(6), None);
+
+    let s = SmallString::from("äöü");
+    assert_eq!(s.char_at(2), Some('ü'));
+    assert_eq!(s.byte_offset_of_char(2), Some(4));
+    let s = SmallString::new();
+    assert_eq!(s.char_at(0), None);
+    assert_eq!(s.byte_offset_of_char(0), Some(0));
+}
+
+#[test]
+fn test_chars_rev() {
+    let s = IString::from("aä€𝄞");
+    assert_eq!(s.chars_rev().collect::<String>(), "𝄞€äa");
+    assert_eq!(s.first_char(), Some('a'));
+    assert_eq!(s.last_char(), Some('𝄞'));
+
+    let mut rev = s.chars_rev();
+    assert_eq!(rev.next_back(), Some('a'));
+    assert_eq!(rev.next(), Some('𝄞'));
+
+    let s = SmallString::from("€x");
+    assert_eq!(s.chars_rev().collect::<String>(), "x€");
+    assert_eq!(s.last_char(), Some('x'));
+
+    let t = TinyString::new("äö").unwrap();
+    assert_eq!(t.chars_rev().collect::<String>(), "öä");
+    assert_eq!((t.first_char(), t.last_char()), (Some('ä'), Some('ö')));
+
+    let e = IString::new();
+    assert_eq!((e.first_char(), e.last_char()), (None, None));
+    assert_eq!(e.chars_rev().next(), None);
+}
+
+#[test]
+fn test_take_string() {
+    let long = "a string that is long enough for the heap";
+    let mut s = IString::from(long);
+    let ptr = s.as_ptr();
+    let taken = s.take_string();
+    assert_eq!(taken, long);
+    assert_eq!(taken.as_ptr(), ptr);
+    assert!(s.is_empty());
+    assert!(s.is_inline());
+
+    s.push_str("abc");
+    assert_eq!(s.take_string(), "abc");
+    assert!(s.is_empty());
+    assert!(s.is_inline());
+    assert_eq!(s.take_string(), "");
+}
+
+#[test]
+fn test_new_inline() {
+    let fits = "x".repeat(IString::INLINE_CAPACITY);
+    let s = IString::new_inline(&fits).unwrap();
+    assert!(s.is_inline());
+    assert_eq!(s, fits.as_str());
+    assert_eq!(IString::new_inline("").unwrap(), "");
+
+    let overflows = "x".repeat(IString::INLINE_CAPACITY + 1);
+    assert!(IString::new_inline(&overflows).is_none());
+    assert!(istring::IBytes::new_inline(overflows.as_bytes()).is_none());
+}
+
+#[test]
+fn test_matches_owned() {
+    let s = IString::from("aaaa ab aaa");
+    for pat in ["aa", "a", "ab", "x", "aaaa ab aaa"] {
+        let owned: Vec<IString> = s.matches_owned(pat).collect();
+        let expected: Vec<&str> = s.matches(pat).collect();
+        assert_eq!(owned, expected);
+        assert!(owned.iter().all(|m| m.is_inline()));
+    }
+
+    let long = "a pattern that is longer than the inline capacity";
+    let s = IString::from(format!("{long}, {long}"));
+    let owned: Vec<IString> = s.matches_owned(long).collect();
+    assert_eq!(owned, [long, long]);
+    assert!(!owned[0].is_inline());
+}
+
+#[test]
+fn test_to_istring() {
+    use std::borrow::Cow;
+    use istring::{ToIString, ToSmallString, ToTinyString};
+
+    assert_eq!("abc".to_istring(), "abc");
+    assert!("abc".to_istring().is_inline());
+    assert_eq!(format!("{}-{}", 1, 2).to_smallstring(), "1-2");
+    assert_eq!(String::from("x").to_istring(), "x");
+    assert_eq!(Cow::Borrowed("cow").to_istring(), "cow");
+    assert_eq!(Cow::<str>::Owned("cow".into()).to_smallstring(), "cow");
+
+    assert_eq!("1234567".to_tinystring().unwrap(), "1234567");
+    assert!("12345678".to_tinystring().is_none());
+    assert!(String::from("äöüß").to_tinystring().is_none());
+
+    let long = "a string that is long enough for the heap";
+    assert_eq!(long.trim().to_istring(), long);
+}
+
+#[test]
+fn test_format_into() {
+    let mut s = IString::from("some text that does not fit inline, to force a heap allocation");
+    let cap = s.capacity();
+    let ptr = s.as_ptr();
+
+    s.format_into(format_args!("{} + {} = {}", 1, 2, 3));
+    assert_eq!(s, "1 + 2 = 3");
+    assert_eq!(s.capacity(), cap);
+    assert_eq!(s.as_ptr(), ptr);
+
+    s.format_into(format_args!("{:>40}", "right aligned"));
+    assert_eq!(s.len(), 40);
+    assert_eq!(s.as_ptr(), ptr);
+
+    let mut s = IString::new();
+    s.format_into(format_args!("{}", 42));
+    assert_eq!(s, "42");
+    assert!(s.is_inline());
+}
+
+#[test]
+fn test_str_like() {
+    use istring::{StrLike, SmallString, TinyString};
+
+    fn total_len<S: StrLike + ?Sized>(items: &[&S]) -> usize {
+        items.iter().map(|s| s.len()).sum()
+    }
+
+    let i = IString::from("abc");
+    let s = SmallString::from("defg");
+    let t = TinyString::new("hi").unwrap();
+    let owned = String::from("jkl");
+
+    assert_eq!(total_len(&[&i, &IString::from("a string long enough for the heap")]), 3 + 33);
+    assert_eq!(total_len(&[&s]), 4);
+    assert_eq!(total_len(&[&t]), 2);
+    assert_eq!(total_len(&[&owned]), 3);
+    assert_eq!(total_len::<str>(&["x", "yz", ""]), 3);
+
+    fn lens(items: &[&dyn StrLike]) -> usize {
+        items.iter().map(|s| s.len()).sum()
+    }
+    assert_eq!(lens(&[&i, &s, &t, &owned]), 3 + 4 + 2 + 3);
+    assert_eq!(StrLike::as_str(&t), "hi");
+    assert!(StrLike::is_empty(""));
+}
+
+#[test]
+fn test_allocated_bytes() {
+    use core::mem::size_of;
+    use istring::{SmallString, TinyString};
+
+    let long = "a string that is long enough to be stored on the heap";
+    let strings: Vec<IString> = vec![
+        "short".into(),
+        long.into(),
+        IString::with_capacity(100),
+        "".into(),
+        IString::from(long).repeat(2),
+    ];
+    let expected: usize = strings.iter().filter_map(|s| s.heap_capacity()).sum();
+    let total: usize = strings.iter().map(|s| s.allocated_bytes()).sum();
+    assert_eq!(total, expected);
+    assert!(total >= long.len() + 100 + 2 * long.len());
+    assert_eq!(strings[0].allocated_bytes(), 0);
+
+    let stored: usize = strings.iter().map(|s| s.size_of_stored()).sum();
+    assert_eq!(stored, total + strings.len() * size_of::<IString>());
+
+    let small = SmallString::from(long);
+    assert_eq!(small.allocated_bytes(), long.len());
+    assert_eq!(SmallString::from("short").allocated_bytes(), 0);
+
+    let tiny = TinyString::new("tiny").unwrap();
+    assert_eq!(tiny.allocated_bytes(), 0);
+    assert_eq!(tiny.size_of_stored(), 8);
+}
+
+#[test]
+fn test_add_cow() {
+    use std::borrow::Cow;
+
+    let mut s = IString::from("a");
+    s += Cow::Borrowed("b");
+    s += Cow::Owned(String::from("c"));
+    s += "d";
+    s += Cow::Owned(format!("{}", 42));
+    assert_eq!(s, "abcd42");
+
+    let s = s + Cow::Borrowed(", and a long tail to move the string to the heap");
+    assert_eq!(s, "abcd42, and a long tail to move the string to the heap");
+    assert!(!s.is_inline());
+    let s = s + Cow::Owned(String::from("!"));
+    assert!(s.ends_with("heap!"));
+}
+
+#[test]
+fn test_escape() {
+    for input in ["", "plain", "tab\tnew\nline", "quote \" and ' and \\", "äöü ❤ \u{1}", "\u{301}accent"] {
+        let s = IString::from(input);
+        assert_eq!(s.escape_default(), input.escape_default().to_string());
+        assert_eq!(s.escape_debug(), input.escape_debug().to_string());
+
+        let s = SmallString::from(input);
+        assert_eq!(s.escape_default(), input.escape_default().to_string());
+        assert_eq!(s.escape_debug(), input.escape_debug().to_string());
+    }
+    assert!(IString::from("a\tb").escape_default().is_inline());
+}
+
+#[test]
+fn test_dedup_char() {
+    let mut s = IString::from("//usr///local//bin/");
+    s.dedup_char('/');
+    assert_eq!(s, "/usr/local/bin/");
+
+    let mut s = IString::from("a/b/c");
+    s.dedup_char('/');
+    assert_eq!(s, "a/b/c");
+    s.dedup_char('x');
+    assert_eq!(s, "a/b/c");
+
+    let mut s = IString::from("ääääbäcää and some text to put it on the heap");
+    let ptr = s.as_ptr();
+    s.dedup_char('ä');
+    assert_eq!(s, "äbäcä and some text to put it on the heap");
+    assert_eq!(s.as_ptr(), ptr);
+
+    // 'ä' and 'ã' share the leading byte
+    let mut s = IString::from("ããäã");
+    s.dedup_char('ã');
+    assert_eq!(s, "ãäã");
+
+    let mut s = IString::from("🦀🦀x🦀🦀🦀");
+    s.dedup_char('🦀');
+    assert_eq!(s, "🦀x🦀");
+
+    let mut s = IString::new();
+    s.dedup_char('a');
+    assert_eq!(s, "");
+}
+
+#[test]
+fn test_for_each_ascii_byte_mut() {
+    fn rot13(b: &mut u8) {
+        match *b {
+            b'a' ..= b'z' => *b = (*b - b'a' + 13) % 26 + b'a',
+            b'A' ..= b'Z' => *b = (*b - b'A' + 13) % 26 + b'A',
+            _ => {}
+        }
+    }
+    let mut s = IString::from("Hello, Wörld!");
+    s.for_each_ascii_byte_mut(rot13);
+    assert_eq!(s, "Uryyb, Jöeyq!");
+    s.for_each_ascii_byte_mut(rot13);
+    assert_eq!(s, "Hello, Wörld!");
+
+    // only ASCII bytes are visited
+    let mut count = 0;
+    s.for_each_ascii_byte_mut(|_| count += 1);
+    assert_eq!(count, 12);
+}
+
+#[test]
+#[should_panic(expected = "not ASCII")]
+fn test_for_each_ascii_byte_mut_invalid() {
+    let mut s = IString::from("abc");
+    s.for_each_ascii_byte_mut(|b| *b = 0xff);
+}
+
+#[test]
+fn test_for_each_ascii_byte_mut_invalid_keeps_utf8() {
+    let mut s = IString::from("abc");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        s.for_each_ascii_byte_mut(|b| if *b == b'b' { *b = 0x80 } else { *b = b'x' });
+    }));
+    assert!(result.is_err());
+    assert_eq!(s, "xbc");
+}
+
+#[test]
+fn test_grow_to() {
+    let mut s = IString::from("abc");
+    s.grow_to(10);
+    assert!(s.is_inline());
+
+    s.grow_to(1000);
+    assert!(!s.is_inline());
+    assert_eq!(s.capacity(), 1000);
+    assert_eq!(s, "abc");
+
+    // grow by fixed chunks
+    s.grow_to(s.capacity() + 4096);
+    assert_eq!(s.capacity(), 5096);
+
+    let ptr = s.as_ptr();
+    s.grow_to(100);
+    assert_eq!(s.capacity(), 5096);
+    assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_insert_char() {
+    let mut s = IString::from("bcd");
+    s.insert_char(0, 'a');
+    assert_eq!(s, "abcd");
+    s.insert_char(2, 'ä');
+    assert_eq!(s, "abäcd");
+    // char index, not byte index: 'ä' is two bytes
+    s.insert_char(4, '-');
+    assert_eq!(s, "abäc-d");
+    s.insert_char(6, '!');
+    assert_eq!(s, "abäc-d!");
+    s.insert_char(100, '🦀');
+    assert_eq!(s, "abäc-d!🦀");
+
+    // crossing the inline boundary
+    let mut s = IString::from("x".repeat(IString::INLINE_CAPACITY).as_str());
+    s.insert_char(1, 'ö');
+    assert!(!s.is_inline());
+    assert_eq!(s.len(), IString::INLINE_CAPACITY + 2);
+    assert!(s.starts_with("xöx"));
+}
+
+mod no_fmt_write_import {
+    use istring::IString;
+
+    #[test]
+    fn test_write_fmt() {
+        let mut s = IString::from("x = ");
+        s.write_fmt(format_args!("{}", 1)).unwrap();
+        assert_eq!(s, "x = 1");
+
+        write!(s, ", y = {:?}", "two").unwrap();
+        writeln!(s).unwrap();
+        assert_eq!(s, "x = 1, y = \"two\"\n");
+
+        s.push_fmt(format_args!("{:03}", 7));
+        assert!(s.ends_with("\n007"));
+    }
+}
+
+#[test]
+fn test_char_boundary_chunks() {
+    let s = IString::from("ab🦀cd🦀🦀e");
+    let chunks: Vec<&str> = s.char_boundary_chunks(4).collect();
+    assert_eq!(chunks, ["ab", "🦀", "cd", "🦀", "🦀", "e"]);
+    assert_eq!(s, chunks.concat());
+
+    let chunks: Vec<&str> = s.char_boundary_chunks(6).collect();
+    assert_eq!(chunks, ["ab🦀", "cd🦀", "🦀e"]);
+    assert!(chunks.iter().all(|c| c.len() <= 6));
+
+    // chars longer than `max_bytes` are yielded on their own
+    let chunks: Vec<&str> = s.char_boundary_chunks(2).collect();
+    assert_eq!(chunks, ["ab", "🦀", "cd", "🦀", "🦀", "e"]);
+    let t = IString::from("äa🦀");
+    let chunks: Vec<&str> = t.char_boundary_chunks(0).collect();
+    assert_eq!(chunks, ["ä", "a", "🦀"]);
+
+    assert_eq!(s.char_boundary_chunks(100).collect::<Vec<_>>(), [s.as_str()]);
+    assert_eq!(IString::new().char_boundary_chunks(4).count(), 0);
+}
+
+#[test]
+fn test_from_code_points_lossy() {
+    let points: Vec<u32> = "hä🦀".chars().map(u32::from).collect();
+    assert_eq!(IString::from_code_points_lossy(&points), "hä🦀");
+    assert_eq!(IString::from_code_points_lossy(&[]), "");
+
+    assert_eq!(IString::from_code_points_lossy(&[0x61, 0x110000, 0x62]), "a\u{FFFD}b");
+    assert_eq!(IString::from_code_points_lossy(&[0xD800, u32::MAX]), "\u{FFFD}\u{FFFD}");
+    assert_eq!(IString::from_code_points_lossy(&[0x10FFFF]), "\u{10FFFF}");
+}
+
+#[test]
+fn test_eq_ignore_ascii_case() {
+    let s = IString::from("Content-Type");
+    assert!(s.eq_ignore_ascii_case("content-type"));
+    assert!(s.eq_ignore_ascii_case("CONTENT-TYPE"));
+    assert!(!s.eq_ignore_ascii_case("content_type"));
+    assert!(!s.eq_ignore_ascii_case("content-type "));
+
+    let other = IString::from("CONTENT-type");
+    assert!(s.eq_ignore_ascii_case(&other));
+    assert!(SmallString::from("X-Header").eq_ignore_ascii_case(&IString::from("x-header")));
+
+    // only ASCII letters are folded
+    assert!(!IString::from("Ärger").eq_ignore_ascii_case("ärger"));
+    assert!(IString::from("Ärger").eq_ignore_ascii_case("ÄRGER"));
+}
+
+#[test]
+fn test_split_once_owned() {
+    let s = IString::from("key=value");
+    let (k, v) = s.split_once_owned('=').unwrap();
+    assert_eq!((k.as_str(), v.as_str()), ("key", "value"));
+    assert!(k.is_inline() && v.is_inline());
+
+    assert_eq!(s.split_once_owned(':'), None);
+    assert_eq!(IString::from("=x").split_once_owned('='), Some(("".into(), "x".into())));
+    assert_eq!(IString::from("x=").split_once_owned('='), Some(("x".into(), "".into())));
+    assert_eq!(IString::from("a=b=c").split_once_owned('='), Some(("a".into(), "b=c".into())));
+
+    let long = "a value that is much too long to be stored inline";
+    let s = IString::from(format!("k→{long}"));
+    let (k, v) = s.split_once_owned('→').unwrap();
+    assert_eq!(k, "k");
+    assert!(k.is_inline());
+    assert_eq!(v, long);
+    assert!(!v.is_inline());
+
+    let (k, v) = SmallString::from("a:b").split_once_owned(':').unwrap();
+    assert_eq!((k, v), (SmallString::from("a"), SmallString::from("b")));
+}
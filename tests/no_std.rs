@@ -0,0 +1,33 @@
+// the macros must work in a crate without std in scope
+#![no_std]
+extern crate std;
+
+use istring::{format_istring, IString};
+
+#[test]
+fn test_format_istring() {
+    let a = 42;
+    let s: IString = format_istring!("{}-{}", a, "b");
+    assert_eq!(s, "42-b");
+    assert!(s.is_inline());
+
+    let s = format_istring!("{:>8}|{:x}|{}", "pad", 255u8, "a string long enough for the heap");
+    assert_eq!(s, "     pad|ff|a string long enough for the heap");
+    assert!(!s.is_inline());
+
+    assert_eq!(format_istring!("plain"), "plain");
+    assert_eq!(format_istring!("{a}"), "42");
+}
+
+struct Failing;
+impl core::fmt::Display for Failing {
+    fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Err(core::fmt::Error)
+    }
+}
+
+#[test]
+#[should_panic(expected = "a formatting trait implementation returned an error")]
+fn test_format_istring_error() {
+    let _ = format_istring!("a{}", Failing);
+}
@@ -0,0 +1,23 @@
+#![cfg(feature="proptest")]
+
+use istring::proptest::{any_istring, any_smallstring, any_tinystring};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_istring_roundtrip(s in any_istring()) {
+        let string: String = s.clone().into();
+        prop_assert_eq!(s, string);
+    }
+
+    #[test]
+    fn test_smallstring_roundtrip(s in any_smallstring()) {
+        let string: String = s.clone().into();
+        prop_assert_eq!(s, string);
+    }
+
+    #[test]
+    fn test_tinystring(s in any_tinystring()) {
+        prop_assert!(s.len() <= istring::TinyString::INLINE_CAPACITY);
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature="quickcheck")]
+
+use istring::IString;
+use quickcheck::{quickcheck, Arbitrary};
+
+#[test]
+fn test_quickcheck_roundtrip() {
+    fn prop(s: String) -> bool {
+        IString::from(s.as_str()).as_str() == s
+    }
+    quickcheck(prop as fn(String) -> bool);
+
+    fn prop_istring(s: IString) -> bool {
+        let string: String = s.clone().into();
+        s == string
+    }
+    quickcheck(prop_istring as fn(IString) -> bool);
+}
+
+#[test]
+fn test_quickcheck_shrink() {
+    let s = IString::from("aé€𝄞");
+    let shrunk: Vec<IString> = s.shrink().collect();
+    assert_eq!(shrunk, ["aé€", "aé", "a", ""]);
+}
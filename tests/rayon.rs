@@ -0,0 +1,26 @@
+#![cfg(feature="rayon")]
+
+use istring::IString;
+use rayon::prelude::*;
+
+#[test]
+fn test_rayon_collect_chars() {
+    let s: IString = (0 .. 10_000u32).into_par_iter()
+        .map(|i| char::from(b'a' + (i % 26) as u8))
+        .collect();
+    let expected: String = (0 .. 10_000u32).map(|i| char::from(b'a' + (i % 26) as u8)).collect();
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_rayon_collect_strings() {
+    let s: IString = (0 .. 1000).into_par_iter()
+        .map(|i| IString::from(i.to_string()))
+        .collect();
+    let expected: String = (0 .. 1000).map(|i| i.to_string()).collect();
+    assert_eq!(s, expected);
+
+    let mut s = IString::from("prefix ");
+    s.par_extend(vec!['a', 'b', 'c']);
+    assert_eq!(s, "prefix abc");
+}
@@ -0,0 +1,36 @@
+#![cfg(feature="rkyv")]
+
+use istring::{IBytes, IString};
+use rkyv::rancor::Error;
+
+#[test]
+fn test_rkyv_istring() {
+    let strings: Vec<IString> = vec![
+        "".into(),
+        "short".into(),
+        "Hello World! this one is stored on the heap".into(),
+    ];
+    let bytes = rkyv::to_bytes::<Error>(&strings).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<IString>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 3);
+    for (a, s) in archived.iter().zip(&strings) {
+        assert_eq!(a, s);
+    }
+    let deserialized: Vec<IString> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, strings);
+}
+
+#[test]
+fn test_rkyv_ibytes() {
+    let data: Vec<IBytes> = vec![
+        IBytes::from(&b"\x00\xff"[..]),
+        IBytes::from(&[0xab; 100][..]),
+    ];
+    let bytes = rkyv::to_bytes::<Error>(&data).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<IBytes>>, Error>(&bytes).unwrap();
+    for (a, b) in archived.iter().zip(&data) {
+        assert_eq!(a, b);
+    }
+    let deserialized: Vec<IBytes> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, data);
+}
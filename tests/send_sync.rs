@@ -0,0 +1,28 @@
+use istring::{IBytes, IString, SmallBytes, SmallString, TinyBytes, TinyString};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_send_sync() {
+    assert_send_sync::<IBytes>();
+    assert_send_sync::<IString>();
+    assert_send_sync::<SmallBytes>();
+    assert_send_sync::<SmallString>();
+    assert_send_sync::<TinyBytes>();
+    assert_send_sync::<TinyString>();
+    assert_send_sync::<istring::HashedIString>();
+    assert_send_sync::<istring::SharedString>();
+}
+
+#[test]
+fn test_share_small_string() {
+    let inline = SmallString::from("short");
+    let heap = SmallString::from("a string that lives on the heap");
+    let (a, b) = (&inline, &heap);
+    std::thread::scope(|scope| {
+        let t1 = scope.spawn(move || a.len() + b.len());
+        let t2 = scope.spawn(move || b.as_str() == "a string that lives on the heap");
+        assert_eq!(t1.join().unwrap(), 5 + 31);
+        assert!(t2.join().unwrap());
+    });
+}
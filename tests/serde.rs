@@ -0,0 +1,83 @@
+#![cfg(feature="serialize")]
+
+use istring::{IBytes, SmallBytes, TinyBytes};
+
+#[test]
+fn test_serde_ibytes() {
+    for data in [&b"short"[..], &[0xff; 100][..]] {
+        let b = IBytes::from(data);
+        let encoded = bincode::serialize(&b).unwrap();
+        // same encoding as a Vec<u8>
+        assert_eq!(encoded, bincode::serialize(&data.to_vec()).unwrap());
+        let decoded: IBytes = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, *data);
+    }
+}
+
+#[test]
+fn test_serde_smallbytes() {
+    for data in [&b"short"[..], &[0xff; 100][..]] {
+        let b = SmallBytes::from(data);
+        let encoded = bincode::serialize(&b).unwrap();
+        let decoded: SmallBytes = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, *data);
+    }
+}
+
+#[test]
+fn test_serde_tinybytes() {
+    let b = TinyBytes::new(&[1, 2, 0xff]).unwrap();
+    let encoded = bincode::serialize(&b).unwrap();
+    let decoded: TinyBytes = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, b);
+
+    let encoded = bincode::serialize(&IBytes::from(&[0u8; 8][..])).unwrap();
+    assert!(bincode::deserialize::<TinyBytes>(&encoded).is_err());
+}
+
+
+#[test]
+fn test_serde_tinystring_borrowed() {
+    use istring::TinyString;
+    use serde::Deserialize;
+    use serde::de::value::{BorrowedStrDeserializer, BytesDeserializer, Error};
+
+    let s = TinyString::deserialize(BorrowedStrDeserializer::<Error>::new("abc")).unwrap();
+    assert_eq!(s, "abc");
+    let err = TinyString::deserialize(BorrowedStrDeserializer::<Error>::new("abcdefgh")).unwrap_err();
+    assert!(err.to_string().contains(&format!("at most {} bytes", TinyString::INLINE_CAPACITY)));
+
+    let s = TinyString::deserialize(BytesDeserializer::<Error>::new(b"xyz")).unwrap();
+    assert_eq!(s, "xyz");
+    assert!(TinyString::deserialize(BytesDeserializer::<Error>::new(b"\xff")).is_err());
+
+    let encoded = bincode::serialize("abc").unwrap();
+    let s: TinyString = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(s, "abc");
+}
+
+#[test]
+fn test_serde_bytes_human_readable() {
+    let b = IBytes::from(&b"hello world"[..]);
+    let json = serde_json::to_string(&b).unwrap();
+    assert_eq!(json, r#""aGVsbG8gd29ybGQ=""#);
+    let decoded: IBytes = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, b"hello world"[..]);
+    assert!(serde_json::from_str::<IBytes>(r#""not base64!""#).is_err());
+
+    let b = SmallBytes::from(&[0xffu8; 20][..]);
+    let decoded: SmallBytes = serde_json::from_str(&serde_json::to_string(&b).unwrap()).unwrap();
+    assert_eq!(decoded, [0xffu8; 20][..]);
+
+    let b = TinyBytes::new(b"abc").unwrap();
+    let json = serde_json::to_string(&b).unwrap();
+    assert_eq!(json, r#""YWJj""#);
+    let decoded: TinyBytes = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, b);
+
+    // binary formats keep the raw bytes
+    let encoded = bincode::serialize(&IBytes::from(&b"abc"[..])).unwrap();
+    assert_eq!(encoded, bincode::serialize(&b"abc".to_vec()).unwrap());
+    let decoded: IBytes = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, b"abc"[..]);
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use istring::{IString, SharedString};
+
+#[test]
+fn test_shared_string() {
+    let short = SharedString::from("short");
+    assert!(short.is_inline());
+    assert_eq!(short, "short");
+    assert_eq!(short.len(), 5);
+
+    let text = "a string that is too long to be stored inline";
+    let long = SharedString::from(text);
+    assert!(!long.is_inline());
+    assert_eq!(long, text);
+
+    let clone = long.clone();
+    assert!(clone.ptr_eq(&long));
+    assert_eq!(clone.as_ptr(), long.as_ptr());
+    assert_eq!(clone, long);
+
+    let copy = SharedString::from(String::from(text));
+    assert!(!copy.ptr_eq(&long));
+    assert_eq!(copy, long);
+
+    let exact = "x".repeat(SharedString::INLINE_CAPACITY);
+    assert!(SharedString::from(exact.as_str()).is_inline());
+    assert!(!SharedString::from(exact.clone() + "x").is_inline());
+
+    let mut set = HashSet::new();
+    set.insert(long.clone());
+    set.insert(short.clone());
+    assert!(set.contains(text));
+    assert!(set.contains("short"));
+
+    assert!(long < short);
+    assert_eq!(format!("{:>7}|{:?}", short, short), "  short|\"short\"");
+    assert_eq!(IString::from(long.clone()), text);
+    assert_eq!(SharedString::from(IString::from(text)), long);
+    assert_eq!(SharedString::default(), "");
+
+    let arc: Arc<str> = Arc::from(text);
+    let s = SharedString::from(arc.clone());
+    assert_eq!(s.as_ptr(), arc.as_ptr());
+}
+
+#[test]
+fn test_shared_string_across_threads() {
+    let s = SharedString::from("shared between threads, long enough for the heap");
+    let handles: Vec<_> = (0 .. 4).map(|_| {
+        let s = s.clone();
+        std::thread::spawn(move || s.len())
+    }).collect();
+    for h in handles {
+        assert_eq!(h.join().unwrap(), s.len());
+    }
+}
+
+#[test]
+fn test_make_mut() {
+    let text = "a string that is too long to be stored inline";
+
+    // unique: modified in place
+    let mut unique = SharedString::from(text);
+    let ptr = unique.as_ptr();
+    unique.make_mut().make_ascii_uppercase();
+    assert_eq!(unique.as_ptr(), ptr);
+    assert_eq!(unique, text.to_ascii_uppercase());
+
+    // shared: copied before writing, the other clone is unchanged
+    let mut a = SharedString::from(text);
+    let b = a.clone();
+    a.make_mut().make_ascii_uppercase();
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert!(!a.ptr_eq(&b));
+    assert_eq!(a, text.to_ascii_uppercase());
+    assert_eq!(b, text);
+
+    // the copy is unique now
+    let ptr = a.as_ptr();
+    a.make_mut().make_ascii_lowercase();
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, text);
+
+    let mut short = SharedString::from("short");
+    let copy = short.clone();
+    short.make_mut().make_ascii_uppercase();
+    assert_eq!(short, "SHORT");
+    assert_eq!(copy, "short");
+}
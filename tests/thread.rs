@@ -2,6 +2,8 @@ extern crate istring;
 use istring::IString;
 
 use std::thread;
+// unused since `IString::write_fmt` is inherent, kept to check that the import still compiles
+#[allow(unused_imports)]
 use std::fmt::Write;
 
 #[test]
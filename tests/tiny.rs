@@ -0,0 +1,145 @@
+use istring::TinyString;
+
+#[test]
+fn test_tiny_push_str() {
+    let mut s = TinyString::new("ab").unwrap();
+    s.push_str("cde").unwrap();
+    assert_eq!(s, "abcde");
+
+    let err = s.push_str("fgh").unwrap_err();
+    assert_eq!((err.needed(), err.available()), (3, 2));
+    assert_eq!(s, "abcde");
+
+    s.push_str("fg").unwrap();
+    assert_eq!(s, "abcdefg");
+    assert!(s.push_str("").is_ok());
+    let err = s.push_str("ä").unwrap_err();
+    assert_eq!((err.needed(), err.available()), (2, 0));
+}
+
+#[test]
+fn test_tiny_try_push() {
+    let mut s = TinyString::new("").unwrap();
+    for c in "abcd".chars() {
+        s.try_push(c).unwrap();
+    }
+    // 3 bytes left, a 4-byte char must not fit
+    assert_eq!(s.try_push('𝄞').unwrap_err().needed(), 4);
+    assert_eq!(s, "abcd");
+    s.try_push('€').unwrap();
+    assert_eq!(s, "abcd€");
+    assert_eq!(s.len(), 7);
+    assert!(s.try_push('x').is_err());
+    assert_eq!(s, "abcd€");
+}
+
+#[test]
+fn test_tiny_try_push_rejected_char() {
+    let mut s = TinyString::new("").unwrap();
+    let mut chars = "abcdefgh".chars();
+    let rejected = loop {
+        let c = chars.next().unwrap();
+        if let Err(e) = s.try_push(c) {
+            break e.rejected_char();
+        }
+    };
+    assert_eq!(s, "abcdefg");
+    assert_eq!(rejected, Some('h'));
+    assert_eq!(s.push_str("h").unwrap_err().rejected_char(), None);
+}
+
+#[test]
+fn test_tiny_repeat() {
+    let s = TinyString::new("ab").unwrap();
+    assert_eq!(s.repeat(0).unwrap(), "");
+    assert_eq!(s.repeat(3).unwrap(), "ababab");
+    let err = s.repeat(4).unwrap_err();
+    assert_eq!((err.needed(), err.available()), (8, 7));
+    assert_eq!(TinyString::new("").unwrap().repeat(usize::MAX).unwrap(), "");
+}
+
+#[test]
+fn test_tiny_widen() {
+    use istring::{IBytes, IString, SmallBytes, SmallString, TinyBytes};
+
+    let t = TinyString::new("1234567").unwrap();
+    let i = IString::from(t);
+    assert_eq!(i, "1234567");
+    assert!(i.is_inline());
+    let s = SmallString::from(t);
+    assert_eq!(s, "1234567");
+    assert_eq!(s.heap_capacity(), None);
+
+    let b = TinyBytes::new(b"abc").unwrap();
+    let i = IBytes::from(b);
+    assert_eq!(i.as_slice(), b"abc");
+    assert!(i.is_inline());
+    assert_eq!(SmallBytes::from(b).as_slice(), b"abc");
+
+    assert_eq!(IString::from(TinyString::new("").unwrap()), "");
+}
+
+#[test]
+fn test_tiny_narrow() {
+    use istring::{IString, SmallString};
+
+    let t = TinyString::try_from(IString::from("1234567")).unwrap();
+    assert_eq!(t, "1234567");
+    let err = TinyString::try_from(IString::from("12345678")).unwrap_err();
+    assert_eq!(err, "12345678");
+
+    assert_eq!(TinyString::try_from(SmallString::from("äöü")).unwrap(), "äöü");
+    assert_eq!(TinyString::try_from(SmallString::from("äöüß")).unwrap_err(), "äöüß");
+}
+
+#[test]
+fn test_capacity_error() {
+    let mut s = TinyString::new("abcdef").unwrap();
+    let err = s.push_str("gh").unwrap_err();
+    assert_eq!(err.to_string(), "capacity exceeded: needed 2 bytes, 1 available");
+
+    #[cfg(feature="std")]
+    {
+        let err: Box<dyn std::error::Error> = Box::new(err);
+        assert_eq!(err.to_string(), "capacity exceeded: needed 2 bytes, 1 available");
+    }
+}
+
+#[test]
+fn test_try_from_utf8() {
+    use istring::{IString, SmallString, TryFromUtf8Error};
+
+    let long = "ä string that is long enough for the heap";
+    assert_eq!(IString::try_from_utf8(long.as_bytes()).unwrap(), long);
+    assert_eq!(SmallString::try_from_utf8("äöü".as_bytes()).unwrap(), "äöü");
+    assert_eq!(TinyString::try_from_utf8("äöü".as_bytes()).unwrap(), "äöü");
+
+    let invalid = [b'a', 0xc3];
+    assert_eq!(IString::try_from_utf8(&invalid).unwrap_err().valid_up_to(), 1);
+    assert!(SmallString::try_from_utf8(&invalid).is_err());
+    assert!(matches!(TinyString::try_from_utf8(&invalid), Err(TryFromUtf8Error::Utf8(_))));
+
+    match TinyString::try_from_utf8(b"12345678") {
+        Err(TryFromUtf8Error::Capacity(e)) => assert_eq!((e.needed(), e.available()), (8, 7)),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_tiny_bytes_arrays() {
+    use istring::TinyBytes;
+
+    let tag = TinyBytes::from(*b"RIFF");
+    assert_eq!(tag, b"RIFF");
+    assert_eq!(tag.len(), 4);
+    assert_eq!(tag.to_array::<4>(), Some(*b"RIFF"));
+    assert_eq!(tag.to_array::<3>(), None);
+    assert_eq!(tag.to_array::<7>(), None);
+
+    assert_eq!(TinyBytes::from([]), b"");
+    assert_eq!(TinyBytes::from([1u8; 7]), [1u8; 7]);
+
+    assert_eq!(TinyBytes::try_from(&b"abc"[..]), Ok(TinyBytes::from(*b"abc")));
+    let err = TinyBytes::try_from(&b"12345678"[..]).unwrap_err();
+    assert_eq!((err.needed(), err.available()), (8, 7));
+}